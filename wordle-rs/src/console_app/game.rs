@@ -5,11 +5,129 @@
 
 use std::{
     borrow::Borrow,
-    collections::HashSet,
-    io::{self, Write},
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    time::{Duration, Instant},
 };
 
-use crate::{players::PlayerInfo, WordleAnswer, WordleGuess, WIN_MESSAGES};
+use super::saved_game::{self, SavedGame};
+
+use crate::{
+    game::{Game, GameOutcome},
+    normalize::{AsciiUppercase, Normalizer},
+    players::PlayerInfo,
+    share::{self, AnsiRenderer, EmojiTheme, LetterRenderer},
+    solver, streak_message,
+    word_list::{AnswersAndGuesses, WordList},
+    GameResult, WordleAnswer, WordleGuess, MAX_GUESSES, WIN_MESSAGES, WORD_LEN,
+};
+
+/// Options for running a game of Wordle.
+///
+/// By default, guesses must be words in the dictionary, the
+/// prompt shows the turn number, and every other option is off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameOptions {
+    /// Allow the player to type ":hint" to reveal a letter.
+    ///
+    /// Each hint uses up a guess, and a game where a hint
+    /// was used is not counted in the player's statistics.
+    pub allow_hints: bool,
+    /// Allow the player to type ":suggest" to get a suggested guess.
+    pub allow_suggestions: bool,
+    /// Redraw the board of all guesses so far after each guess.
+    pub show_board: bool,
+    /// Clear the screen before redrawing the board.
+    pub clear_screen: bool,
+    /// Require every guess to use all hints revealed so far.
+    ///
+    /// Green letters must stay in place, and yellow letters
+    /// must be used again.
+    pub hard_mode: bool,
+    /// Play a practice game, which is not counted
+    /// in the player's statistics.
+    ///
+    /// A practice game is never saved to be resumed, so
+    /// it does not replace a saved game that is counted.
+    pub practice: bool,
+    /// Other spellings accepted in place of an answer, such as
+    /// "GRAY" for "GREY".
+    ///
+    /// Each answer maps to the words that are accepted as correct
+    /// when it is the answer. To accept two spellings for each
+    /// other, add each one as an alias of the other.
+    pub aliases: HashMap<String, HashSet<String>>,
+    /// Save the game after each guess so it can be resumed
+    /// if the program is closed before the game ends.
+    ///
+    /// The game is saved to the file named by
+    /// [`saved_game::saved_game_filename`], which is
+    /// deleted when the game ends.
+    pub allow_resume: bool,
+    /// Show how long the game took in the summary
+    /// printed at the end of the game.
+    pub show_timing: bool,
+    /// Show nothing during the game except a final result line.
+    ///
+    /// The prompts and messages of the login screen and the main
+    /// menu are not shown either, including any errors that do not
+    /// end the program, so the console application can be driven
+    /// by a script that only reads the results. Statistics and the
+    /// leaderboard are still shown when they are chosen from the menu.
+    /// See [`result_line`] for the format of the result.
+    pub quiet: bool,
+    /// List the existing users with the most recent logins first
+    /// when asking for a username.
+    ///
+    /// Otherwise, the users are listed in alphabetical order.
+    pub sort_users_by_recency: bool,
+    /// Warn the player when they repeat a guess from the same game,
+    /// and ask for another guess without using up a turn.
+    pub warn_repeat_guess: bool,
+    /// Require every guess to be a word in the dictionary.
+    ///
+    /// If this is false, any guess of the right number of
+    /// letters is accepted. Answers still come from the dictionary.
+    pub validate_guesses: bool,
+    /// Words accepted as guesses in addition to the dictionary.
+    ///
+    /// These words are never answers, since answers are only
+    /// chosen from the dictionary. This allows a large list of
+    /// guesses to be used with a smaller list of answers.
+    pub allowed_guesses: HashSet<String>,
+    /// Colors of the emoji squares used to share the game.
+    pub emoji_theme: EmojiTheme,
+    /// Prompt shown before reading each guess.
+    ///
+    /// Any `{n}` in the prompt is replaced with the turn number.
+    /// The prompt is not shown in quiet mode.
+    pub prompt: String,
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        Self {
+            allow_hints: false,
+            allow_suggestions: false,
+            show_board: false,
+            clear_screen: false,
+            hard_mode: false,
+            practice: false,
+            aliases: HashMap::new(),
+            allow_resume: false,
+            show_timing: false,
+            quiet: false,
+            sort_users_by_recency: false,
+            warn_repeat_guess: false,
+            validate_guesses: true,
+            allowed_guesses: HashSet::new(),
+            emoji_theme: EmojiTheme::Standard,
+            prompt: String::from("[{n}] "),
+        }
+    }
+}
 
 /// Runs a game of Wordle.
 ///
@@ -19,7 +137,56 @@ use crate::{players::PlayerInfo, WordleAnswer, WordleGuess, WIN_MESSAGES};
 /// the answer or used all six guesses, whichever is first.
 ///
 /// At the start of the game, a message is printed with
-/// instructions for the player.
+/// instructions for the player. At the end of the game,
+/// a grid of the results is printed for sharing, along with
+/// the puzzle number if the game is a numbered puzzle.
+///
+/// The dictionary can be any [`WordList`]. Suggestions are chosen
+/// from the words it gives with [`WordList::sorted_words`], so
+/// they are not available if it gives none, such as for a list
+/// that cannot be listed.
+///
+/// Answers and guesses are both normalized with [`AsciiUppercase`],
+/// so the words in the dictionary should be uppercase.
+///
+/// If hints are enabled in the game options, the player may
+/// type ":hint" instead of a guess to reveal one letter of the
+/// answer that they have not yet found. This uses up a guess,
+/// and the game is not counted in the player's statistics.
+///
+/// If the board is enabled in the game options, every guess so
+/// far is printed after each guess, instead of only the colors
+/// of the latest guess.
+///
+/// If suggestions are enabled in the game options, the player
+/// may type ":suggest" to be given the best next guess based
+/// on their guesses so far. This does not use up a guess.
+///
+/// If hard mode is enabled in the game options, each guess must
+/// use all the hints revealed by earlier guesses. A guess that
+/// does not is rejected and recorded as a hard mode violation.
+///
+/// If the game options give aliases for the answer, guessing
+/// one of them wins the game, just like guessing the answer.
+///
+/// If practice is enabled in the game options, the game and any
+/// hard mode violations are not counted in the player's statistics.
+///
+/// If resuming is enabled in the game options and the game is not
+/// a practice game, the game is saved after each guess, so it can
+/// be continued with [`resume`] if the player quits before the
+/// game ends.
+///
+/// Guesses are checked against `dictionary` along with the allowed
+/// guesses in the game options, but only words in `dictionary`
+/// can be answers, so only they are suggested.
+///
+/// The player can quit the game by entering ":q" instead of a guess,
+/// in which case the game is not counted in their statistics, and
+/// any saved progress is deleted so it cannot be resumed.
+///
+/// The result of the game is returned, or None if the player quit
+/// before the game ended.
 ///
 /// # Panics
 ///
@@ -31,7 +198,7 @@ use crate::{players::PlayerInfo, WordleAnswer, WordleGuess, WIN_MESSAGES};
 /// ```no_run
 /// # use std::{collections::HashSet, io};
 /// # use wordle::{
-/// #     console_app::game,
+/// #     console_app::game::{self, GameOptions},
 /// #     players::PlayerInfo,
 /// #     WordleAnswer,
 /// # };
@@ -46,88 +213,1425 @@ use crate::{players::PlayerInfo, WordleAnswer, WordleGuess, WIN_MESSAGES};
 ///
 /// if let Some(mut player) = player {
 ///     // runs one game of Wordle where the answer is "TRACE"
-///     game::run(&answer, &mut player, &dictionary);
+///     game::run(&answer, None, &mut player, &dictionary, &GameOptions::default());
 /// }
 /// # Ok(())
 /// # }
 /// ```
 pub fn run(
     answer: &WordleAnswer<impl Borrow<str>>,
+    puzzle_id: Option<u32>,
     player: &mut PlayerInfo<impl Borrow<str>>,
-    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
-) {
-    {
-        let mut lock = io::stdout().lock();
-        writeln!(lock, "Guess the 5-letter word in 6 or fewer guesses.").unwrap();
-        writeln!(lock, "After each guess, each letter will be given a color:").unwrap();
-        writeln!(lock, "G = Green:\tletter is in that position in the word").unwrap();
+    dictionary: &impl WordList,
+    options: &GameOptions,
+) -> Option<GameResult> {
+    run_with(answer, puzzle_id, player, dictionary, options, |turn| {
+        read_guess(turn, options)
+    })
+}
+
+/// Runs a game of Wordle, getting each guess from a closure.
+///
+/// This works the same as [`run`], except that guesses are not
+/// read from stdin. Instead, `get_guess` is called with the turn
+/// number (starting at 1) whenever a guess is needed, and returns
+/// the next guess, or None to quit the game. A guess of ":q" also
+/// quits the game, but deletes any saved progress as well. If a
+/// guess is not valid, `get_guess` is called again for the same
+/// turn. All output is still written to stdout.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::{
+/// #     console_app::game::{self, GameOptions},
+/// #     players::PlayerInfo,
+/// #     WordleAnswer,
+/// # };
+/// let dictionary = HashSet::from([String::from("CRATE"), String::from("TRACE")]);
+/// let answer = WordleAnswer::new("TRACE");
+/// let mut player = PlayerInfo::new("user");
+///
+/// let mut guesses = ["CRATE", "TRACE"].into_iter();
+/// game::run_with(
+///     &answer,
+///     None,
+///     &mut player,
+///     &dictionary,
+///     &GameOptions::default(),
+///     |_| guesses.next().map(str::to_owned),
+/// );
+/// assert_eq!(player.games_won(), 1);
+/// ```
+pub fn run_with<F: FnMut(usize) -> Option<String>>(
+    answer: &WordleAnswer<impl Borrow<str>>,
+    puzzle_id: Option<u32>,
+    player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &impl WordList,
+    options: &GameOptions,
+    get_guess: F,
+) -> Option<GameResult> {
+    play(
+        answer,
+        puzzle_id,
+        player,
+        dictionary,
+        options,
+        get_guess,
+        &[],
+        0,
+        &mut io::stdout(),
+    )
+}
+
+/// Resumes a saved game of Wordle.
+///
+/// This works the same as [`run`], except that the game continues
+/// from the guesses and hints in the saved game.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::{collections::HashSet, io};
+/// # use wordle::{
+/// #     console_app::{game::{self, GameOptions}, saved_game::{self, SavedGame}},
+/// #     players::PlayerInfo,
+/// # };
+/// # fn main() -> io::Result<()> {
+/// let dictionary = HashSet::from([String::from("CRATE"), String::from("TRACE")]);
+/// let mut player = PlayerInfo::new("user");
+///
+/// if let Some(saved) = SavedGame::from_file(&saved_game::saved_game_filename("user"))? {
+///     game::resume(&saved, &mut player, &dictionary, &GameOptions::default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn resume(
+    saved: &SavedGame,
+    player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &impl WordList,
+    options: &GameOptions,
+) -> Option<GameResult> {
+    resume_with(saved, player, dictionary, options, |turn| {
+        read_guess(turn, options)
+    })
+}
+
+/// Resumes a saved game of Wordle, getting each guess from a closure.
+///
+/// This works the same as [`run_with`], except that the game
+/// continues from the guesses and hints in the saved game.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::{
+/// #     console_app::{game::{self, GameOptions}, saved_game::SavedGame},
+/// #     players::PlayerInfo,
+/// # };
+/// let dictionary = HashSet::from([String::from("CRATE"), String::from("TRACE")]);
+/// let mut player = PlayerInfo::new("user");
+/// let saved = SavedGame {
+///     answer: String::from("TRACE"),
+///     puzzle_id: None,
+///     guesses: vec![String::from("CRATE")],
+///     num_hints: 0,
+/// };
+///
+/// let mut guesses = ["TRACE"].into_iter();
+/// game::resume_with(
+///     &saved,
+///     &mut player,
+///     &dictionary,
+///     &GameOptions::default(),
+///     |_| guesses.next().map(str::to_owned),
+/// );
+/// assert_eq!(player.stats().distribution, [0, 1, 0, 0, 0, 0]);
+/// ```
+pub fn resume_with<F: FnMut(usize) -> Option<String>>(
+    saved: &SavedGame,
+    player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &impl WordList,
+    options: &GameOptions,
+    get_guess: F,
+) -> Option<GameResult> {
+    let answer = WordleAnswer::normalized(&saved.answer, &AsciiUppercase);
+    play(
+        &answer,
+        saved.puzzle_id,
+        player,
+        dictionary,
+        options,
+        get_guess,
+        &saved.guesses,
+        saved.num_hints,
+        &mut io::stdout(),
+    )
+}
+
+/// Reads a guess from stdin, showing the prompt from the
+/// game options unless the game is in quiet mode.
+///
+/// If reading fails, or there is no more input, this
+/// function returns None.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+pub(super) fn read_guess(turn: usize, options: &GameOptions) -> Option<String> {
+    read_guess_from(turn, options, &mut io::stdin().lock(), &mut io::stdout())
+}
+
+/// Reads a guess from `input`, writing the prompt to `out`.
+///
+/// See [`read_guess`].
+fn read_guess_from(
+    turn: usize,
+    options: &GameOptions,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> Option<String> {
+    if !options.quiet {
+        write!(out, "{}", format_prompt(&options.prompt, turn)).unwrap();
+        out.flush().unwrap();
+    }
+
+    let mut guess = String::new();
+    match input.read_line(&mut guess) {
+        /* user likely quit the program with Ctrl-C or closed the input */
+        Err(_) | Ok(0) => None,
+        Ok(_) => Some(guess),
+    }
+}
+
+/// Creates the prompt for a turn, replacing every `{n}`
+/// in the prompt with the turn number.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::console_app::game;
+/// assert_eq!(game::format_prompt("[{n}] ", 3), "[3] ");
+/// assert_eq!(game::format_prompt("Guess {n}/6: ", 2), "Guess 2/6: ");
+/// ```
+#[must_use]
+pub fn format_prompt(prompt: &str, turn: usize) -> String {
+    prompt.replace("{n}", &turn.to_string())
+}
+
+/// Plays a game of Wordle, starting from the given guesses and
+/// number of hints already used.
+///
+/// Everything the game shows is written to `output`, which
+/// only gets the final result line in quiet mode.
+///
+/// # Panics
+///
+/// Panics if writing to `output` fails.
+#[allow(clippy::too_many_arguments)]
+fn play<F: FnMut(usize) -> Option<String>>(
+    answer: &WordleAnswer<impl Borrow<str>>,
+    puzzle_id: Option<u32>,
+    player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &impl WordList,
+    options: &GameOptions,
+    mut get_guess: F,
+    saved_guesses: &[String],
+    saved_num_hints: usize,
+    output: &mut dyn Write,
+) -> Option<GameResult> {
+    let words = AnswersAndGuesses {
+        answers: dictionary,
+        guesses: &options.allowed_guesses,
+    };
+    let guesses = GuessList {
+        words: &words,
+        validate: options.validate_guesses,
+    };
+    let aliases = options
+        .aliases
+        .get(answer.get_word())
+        .cloned()
+        .unwrap_or_default();
+    let mut game = Game::new(WordleAnswer::new(answer.get_word()), &guesses).with_aliases(aliases);
+    let context = TurnContext::new(puzzle_id, dictionary, options, player.get_username());
+    /* nothing but the result is shown in quiet mode */
+    let mut sink = io::sink();
+    let mut out: &mut dyn Write = if options.quiet {
+        &mut sink
+    } else {
+        &mut *output
+    };
+    writeln!(
+        out,
+        "Guess the {WORD_LEN}-letter word in {MAX_GUESSES} or fewer guesses."
+    )
+    .unwrap();
+    writeln!(out, "After each guess, each letter will be given a color:").unwrap();
+    writeln!(out, "G = Green:\tletter is in that position in the word").unwrap();
+    writeln!(
+        out,
+        "Y = Yellow:\tletter is in the word, but not that position"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "X = Black:\tthere are no more instances of the letter in the word"
+    )
+    .unwrap();
+    if options.allow_hints {
+        writeln!(out, "Type \":hint\" to reveal a letter, using up a guess").unwrap();
+    }
+    if context.can_suggest() {
+        writeln!(out, "Type \":suggest\" to get a suggested guess").unwrap();
+    } else if options.allow_suggestions {
         writeln!(
-            lock,
-            "Y = Yellow:\tletter is in the word, but not that position"
+            out,
+            "Suggestions are not available, since the words cannot be listed"
         )
         .unwrap();
+    }
+    if options.hard_mode {
+        writeln!(out, "Hard mode: every guess must use all revealed hints").unwrap();
+    }
+    writeln!(out, "Type \":q\" to quit the game without counting it").unwrap();
+    writeln!(out).unwrap();
+
+    /* restore the guesses and hints from a saved game */
+    for guess in saved_guesses {
+        /* saved guesses were accepted when they were made, so they are not checked again */
+        _ = game.restore_guess(guess);
+    }
+    for _ in 0..saved_num_hints {
+        if let Some((pos, letter)) = game.reveal_hint() {
+            writeln!(out, "    Hint: letter {} is {letter}", pos + 1).unwrap();
+        }
+    }
+    if !game.history().is_empty() {
         writeln!(
-            lock,
-            "X = Black:\tthere are no more instances of the letter in the word"
+            out,
+            "{}",
+            render_board(game.history(), MAX_GUESSES.saturating_sub(game.num_hints()))
         )
         .unwrap();
-        writeln!(lock).unwrap();
+    }
+    let start = Instant::now();
+
+    while game.is_over().is_none() {
+        let turn = MAX_GUESSES - game.remaining() + 1;
+        if play_turn(turn, &context, &mut game, player, &mut get_guess, &mut out)
+            == TurnOutcome::Quit
+        {
+            return None;
+        }
     }
 
-    let won_game = (1..=6).find_map(|i| {
-        let mut guess = String::new();
-        let guess = loop {
-            print!("[{i}] ");
-            io::stdout().flush().unwrap();
+    let elapsed = options.show_timing.then(|| start.elapsed());
+    let used_hint = game.num_hints() > 0;
+    let counted = !used_hint && !options.practice;
 
-            guess.clear();
-            if io::stdin().read_line(&mut guess).is_err() {
-                /* user likely quit the program with Ctrl-C */
-                return Some(-1);
+    match game.is_over() {
+        Some(GameOutcome::Won(num_guesses)) => {
+            if counted {
+                player.add_won_word(answer.get_word().to_owned(), num_guesses);
             }
-            guess.make_ascii_uppercase();
-            let guess = guess.trim();
-            if guess.len() != 5 {
-                println!("Error: guess must be 5 letters");
-            } else if !dictionary.contains(guess) {
-                println!("Error: guess must be a word in the dictionary");
-            } else {
-                /* valid guess, stop the read loop */
-                break guess;
+            write!(out, "{}! ", WIN_MESSAGES[num_guesses - 1]).unwrap();
+        }
+        _ => {
+            if counted {
+                player.add_lost_word(answer.get_word().to_owned());
             }
+            write!(out, "Too bad! ").unwrap();
+        }
+    }
+    if context.saves_progress() {
+        /* the game is over, so there is nothing left to resume */
+        _ = fs::remove_file(&context.saved_game_filename);
+    }
+    writeln!(out, "The word was: {}", answer.get_word()).unwrap();
+    if options.practice {
+        writeln!(out, "This was a practice game, so it was not counted").unwrap();
+    } else if used_hint {
+        writeln!(out, "A hint was used, so this game was not counted").unwrap();
+    } else if game.is_over() != Some(GameOutcome::Lost) {
+        if let Some(message) = streak_message(player.get_cur_win_streak()) {
+            writeln!(out, "{message}!").unwrap();
+        }
+    }
+    writeln!(out).unwrap();
+
+    let result = game.into_result();
+    writeln!(out, "{}", game_summary_line(&result, elapsed)).unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "{}",
+        share::game_share_text_with(
+            &result,
+            puzzle_id,
+            None::<&PlayerInfo<&str>>,
+            options.emoji_theme
+        )
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    if options.quiet {
+        writeln!(output, "{}", result_line(&result)).unwrap();
+    }
+
+    Some(result)
+}
+
+/// Possible results of a single turn in a game of Wordle.
+///
+/// Whether the turn ended the game is found from the [`Game`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TurnOutcome {
+    /// The player made a guess or used a hint
+    Played,
+    /// The player quit the game
+    Quit,
+}
+
+/// The words accepted as guesses in a game of Wordle.
+///
+/// If guesses are not checked against the dictionary, every
+/// word is accepted, as long as it is a word of letters.
+struct GuessList<'a, W> {
+    words: &'a W,
+    validate: bool,
+}
+
+impl<W: WordList> WordList for GuessList<'_, W> {
+    fn is_valid(&self, word: &str) -> bool {
+        !self.validate || self.words.is_valid(word)
+    }
+}
+
+/// Everything about a game of Wordle that stays the same
+/// from one turn to the next.
+struct TurnContext<'a> {
+    puzzle_id: Option<u32>,
+    /* only used to make suggestions */
+    sorted_dictionary: Vec<String>,
+    options: &'a GameOptions,
+    saved_game_filename: String,
+    /* only color the results of guesses if they are shown on a terminal */
+    color: bool,
+}
+
+impl<'a> TurnContext<'a> {
+    /// Creates the context for a game played by the given user.
+    ///
+    /// Only words in `answers` are suggested, since a word that
+    /// is only an allowed guess can never be the answer.
+    fn new(
+        puzzle_id: Option<u32>,
+        answers: &impl WordList,
+        options: &'a GameOptions,
+        username: &str,
+    ) -> Self {
+        let sorted_dictionary = if options.allow_suggestions {
+            answers.sorted_words()
+        } else {
+            Vec::new()
         };
 
-        let colors = answer.check_guess(guess);
-        {
-            let mut lock = io::stdout().lock();
-            write!(lock, "    ").unwrap();
-            for color in colors {
-                write!(lock, "{color}").unwrap();
-            }
-            writeln!(lock).unwrap();
+        Self {
+            puzzle_id,
+            sorted_dictionary,
+            options,
+            saved_game_filename: saved_game::saved_game_filename(username),
+            color: !options.quiet && io::stdout().is_terminal(),
         }
+    }
+
+    /// Checks whether the game is saved so it can be resumed.
+    ///
+    /// A practice game is never saved, since resuming it
+    /// would count it in the player's statistics.
+    fn saves_progress(&self) -> bool {
+        self.options.allow_resume && !self.options.practice
+    }
+
+    /// Checks whether the player can ask for a suggested guess.
+    ///
+    /// Suggestions are chosen from the list of answers, so they
+    /// are not available if the word list cannot list its words
+    /// (see [`WordList::sorted_words`]).
+    fn can_suggest(&self) -> bool {
+        self.options.allow_suggestions && !self.sorted_dictionary.is_empty()
+    }
+}
 
-        if colors.into_iter().all(|c| c == WordleGuess::Correct) {
-            Some(i)
+/// Plays a single turn of a game of Wordle.
+///
+/// The player is asked for guesses until they make a valid
+/// guess, use a hint, or quit. The game is saved after the
+/// turn if it is not over, so it can be resumed.
+///
+/// # Panics
+///
+/// Panics if writing to `out` fails.
+fn play_turn(
+    turn: usize,
+    context: &TurnContext,
+    game: &mut Game<impl Borrow<str>, impl WordList>,
+    player: &mut PlayerInfo<impl Borrow<str>>,
+    get_guess: &mut impl FnMut(usize) -> Option<String>,
+    out: &mut dyn Write,
+) -> TurnOutcome {
+    let options = context.options;
+    let save_progress = |game: &Game<_, _>| {
+        if context.saves_progress() && game.is_over().is_none() {
+            save_progress(
+                &context.saved_game_filename,
+                game.answer(),
+                context.puzzle_id,
+                game.history(),
+                game.num_hints(),
+            );
+        }
+    };
+
+    let colors = loop {
+        let Some(input) = get_guess(turn) else {
+            return TurnOutcome::Quit;
+        };
+        let guess = normalize_guess(&input);
+        if guess == ":Q" {
+            /* the player quit the game, so nothing is counted or left to resume */
+            if context.saves_progress() {
+                _ = fs::remove_file(&context.saved_game_filename);
+            }
+            return TurnOutcome::Quit;
+        } else if options.allow_hints && guess == ":HINT" {
+            match game.reveal_hint() {
+                Some((pos, letter)) => {
+                    writeln!(out, "    Hint: letter {} is {letter}", pos + 1).unwrap();
+                    save_progress(game);
+                    /* the hint uses up this guess */
+                    return TurnOutcome::Played;
+                }
+                None => writeln!(out, "Error: there are no letters left to reveal").unwrap(),
+            }
+        } else if options.allow_suggestions && guess == ":SUGGEST" {
+            if !context.can_suggest() {
+                writeln!(
+                    out,
+                    "Error: suggestions are not available, since the words cannot be listed"
+                )
+                .unwrap();
+                continue;
+            }
+            match solver::suggest(game.history(), &context.sorted_dictionary) {
+                Some(suggestion) => writeln!(out, "    Suggestion: {suggestion}").unwrap(),
+                None => {
+                    writeln!(out, "Error: no words in the dictionary match your guesses").unwrap();
+                }
+            }
+        } else if game.is_alias(&guess) {
+            /* an alias is always accepted, even if it is not in the dictionary */
+            break game.submit_guess(&guess).unwrap();
+        } else if options.warn_repeat_guess && game.history().iter().any(|(g, _)| *g == guess) {
+            writeln!(out, "You already guessed that word").unwrap();
+        } else if let Err(err) = game.validate_guess(&guess) {
+            writeln!(out, "Error: {err}").unwrap();
+        } else if options.hard_mode && !solver::hard_mode_allows(game.history(), &guess) {
+            if !options.practice {
+                player.add_hard_mode_violation();
+            }
+            writeln!(out, "Error: guess must use all revealed hints").unwrap();
         } else {
-            None
+            /* valid guess, stop the read loop */
+            break game.submit_guess(&guess).unwrap();
         }
-    });
+    };
+
+    if options.show_board {
+        if options.clear_screen {
+            /* clear the screen and move the cursor to the top */
+            write!(out, "\x1b[2J\x1b[H").unwrap();
+        }
+        /* each hint used up one of the rows on the board */
+        writeln!(
+            out,
+            "{}",
+            render_board(game.history(), MAX_GUESSES.saturating_sub(game.num_hints()))
+        )
+        .unwrap();
+    } else {
+        writeln!(out, "    {}", render_colors(&colors, context.color)).unwrap();
+    }
 
-    let mut lock = io::stdout().lock();
+    save_progress(game);
+    TurnOutcome::Played
+}
 
-    match won_game {
-        Some(i) if i > 0 => {
-            player.add_won_word(answer.get_word().to_owned(), i as usize);
-            write!(lock, "{}! ", WIN_MESSAGES[i as usize - 1]).unwrap();
+/// Creates a machine-readable line describing the result of a game.
+///
+/// The line has the form "RESULT WON 4 TRACE" for a game won in
+/// four guesses, or "RESULT LOST 6 TRACE" for a game that was lost
+/// after six guesses. This is the only output of a game in quiet mode.
+/// Turns used up by hints are counted, like in [`game_summary_line`].
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{console_app::game, guess_result, GameResult};
+/// let result = GameResult {
+///     answer: String::from("TRACE"),
+///     history: vec![(String::from("TRACE"), guess_result![G G G G G])],
+///     num_guesses: Some(1),
+/// };
+/// assert_eq!(game::result_line(&result), "RESULT WON 1 TRACE");
+/// ```
+#[must_use]
+pub fn result_line(result: &GameResult) -> String {
+    let outcome = if result.num_guesses.is_some() {
+        "WON"
+    } else {
+        "LOST"
+    };
+    let turns = result.num_guesses.unwrap_or(MAX_GUESSES);
+    format!("RESULT {outcome} {turns} {}", result.answer)
+}
+
+/// Creates a one-line summary of a finished game.
+///
+/// The summary shows the number of turns used, how long the
+/// game took if `elapsed` is given, and whether the game was won.
+/// Turns used up by hints are counted, so a lost game always
+/// used every turn.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::time::Duration;
+/// # use wordle::{console_app::game, guess_result, GameResult};
+/// let result = GameResult {
+///     answer: String::from("TRACE"),
+///     history: vec![(String::from("TRACE"), guess_result![G G G G G])],
+///     num_guesses: Some(1),
+/// };
+///
+/// assert_eq!(
+///     game::game_summary_line(&result, Some(Duration::from_secs(65))),
+///     "Turns: 1/6 | Time: 1m 05s | Result: Won"
+/// );
+/// ```
+#[must_use]
+pub fn game_summary_line(result: &GameResult, elapsed: Option<Duration>) -> String {
+    let turns = result.num_guesses.unwrap_or(MAX_GUESSES);
+    let mut summary = format!("Turns: {turns}/{MAX_GUESSES}");
+    if let Some(elapsed) = elapsed {
+        let secs = elapsed.as_secs();
+        match secs / 60 {
+            0 => write!(summary, " | Time: {secs}s").unwrap(),
+            mins => write!(summary, " | Time: {mins}m {:02}s", secs % 60).unwrap(),
+        }
+    }
+    let outcome = if result.num_guesses.is_some() {
+        "Won"
+    } else {
+        "Lost"
+    };
+    write!(summary, " | Result: {outcome}").unwrap();
+    summary
+}
+
+/// Saves a game in progress so it can be resumed later.
+///
+/// Saving is best effort, so any errors are ignored.
+fn save_progress(
+    filename: &str,
+    answer: &WordleAnswer<impl Borrow<str>>,
+    puzzle_id: Option<u32>,
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    num_hints: usize,
+) {
+    let saved = SavedGame {
+        answer: answer.get_word().to_owned(),
+        puzzle_id,
+        guesses: history.iter().map(|(guess, _)| guess.clone()).collect(),
+        num_hints,
+    };
+    _ = saved.write_to_file(filename);
+}
+
+/// Renders the colors of a guess as a row.
+///
+/// If `color` is true, each letter is shown on a background of
+/// its color using ANSI escape codes. Otherwise, the letters are
+/// shown as plain text, so output that is not shown on a terminal
+/// stays readable.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{console_app::game, guess_result};
+/// let colors = guess_result![G Y X X G];
+/// assert_eq!(game::render_colors(&colors, false), "GYXXG");
+/// assert!(game::render_colors(&colors, true).starts_with("\x1b[30;42mG"));
+/// ```
+#[must_use]
+pub fn render_colors(colors: &[WordleGuess; WORD_LEN], color: bool) -> String {
+    if color {
+        share::render_row(&AnsiRenderer, colors)
+    } else {
+        share::render_row(&LetterRenderer, colors)
+    }
+}
+
+/// Renders a board of guesses and their colors.
+///
+/// Each guess is shown on its own row, followed by its colors.
+/// Rows that have not been guessed yet are shown as blank
+/// placeholders, up to the maximum number of guesses.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{console_app::game, guess_result};
+/// let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+/// let board = game::render_board(&history, 2);
+/// assert_eq!(board, "    CRATE YGGYG\n    _____ _____");
+/// ```
+#[must_use]
+pub fn render_board(history: &[(String, [WordleGuess; WORD_LEN])], max_guesses: usize) -> String {
+    let mut board = String::new();
+    for (guess, colors) in history {
+        write!(board, "    {guess} ").unwrap();
+        for color in colors {
+            write!(board, "{color}").unwrap();
+        }
+        writeln!(board).unwrap();
+    }
+    let blank = "_".repeat(WORD_LEN);
+    for _ in history.len()..max_guesses {
+        writeln!(board, "    {blank} {blank}").unwrap();
+    }
+    board.trim_end().to_owned()
+}
+
+/// Normalizes a guess entered by the user.
+///
+/// All whitespace, including whitespace between letters,
+/// is removed, and the guess is converted to uppercase with
+/// [`AsciiUppercase`], the same as the answers.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::console_app::game;
+/// assert_eq!(game::normalize_guess("t r a c e\n"), "TRACE");
+/// ```
+#[must_use]
+pub fn normalize_guess(guess: &str) -> String {
+    let guess: String = guess.chars().filter(|c| !c.is_whitespace()).collect();
+    AsciiUppercase.normalize(&guess)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{guess_result, validate_guess, GuessError};
+
+    #[test]
+    fn spaced_guess_is_normalized() {
+        assert_eq!(normalize_guess("t r a c e"), "TRACE");
+        assert_eq!(normalize_guess("  TrAcE \r\n"), "TRACE");
+    }
+
+    #[test]
+    fn tabbed_guess_is_normalized() {
+        assert_eq!(normalize_guess("t\tr\ta\tc\te"), "TRACE");
+    }
+
+    #[test]
+    fn wrong_length_is_kept() {
+        assert_eq!(normalize_guess("t r a c"), "TRAC");
+        assert_eq!(normalize_guess("t r a c e s"), "TRACES");
+    }
+
+    #[test]
+    fn plain_colors_when_not_a_terminal() {
+        let colors = guess_result![G Y X X G];
+        assert_eq!(render_colors(&colors, false), "GYXXG");
+        assert_eq!(
+            render_colors(&colors, true),
+            "\x1b[30;42mG\x1b[0m\x1b[30;43mY\x1b[0m\x1b[30;47mX\x1b[0m\
+            \x1b[30;47mX\x1b[0m\x1b[30;42mG\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn board_for_partial_game() {
+        let answer = WordleAnswer::new("TRACE");
+        let history: Vec<_> = ["BLIND", "CRATE"]
+            .into_iter()
+            .map(|guess| (guess.to_owned(), answer.check_guess(guess)))
+            .collect();
+
+        let expected = [
+            "    BLIND XXXXX",
+            "    CRATE YGGYG",
+            "    _____ _____",
+            "    _____ _____",
+            "    _____ _____",
+            "    _____ _____",
+        ];
+        assert_eq!(render_board(&history, 6), expected.join("\n"));
+    }
+
+    #[test]
+    fn game_driven_by_closure() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+
+        /* an invalid guess is asked for again on the same turn */
+        let mut guesses = ["BLIND", "NOTAWORD", "CRATE", "TRACE"].into_iter();
+        let mut turns = Vec::new();
+        run_with(
+            &answer,
+            None,
+            &mut player,
+            &dictionary,
+            &GameOptions::default(),
+            |turn| {
+                turns.push(turn);
+                guesses.next().map(str::to_owned)
+            },
+        );
+
+        assert_eq!(turns, [1, 2, 2, 3]);
+        assert_eq!(player.games_won(), 1);
+        assert_eq!(player.stats().distribution, [0, 0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn closure_quits_game() {
+        let dictionary: HashSet<_> = ["CRATE"].into_iter().map(str::to_owned).collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+
+        let mut guesses = ["CRATE"].into_iter();
+        run_with(
+            &answer,
+            None,
+            &mut player,
+            &dictionary,
+            &GameOptions::default(),
+            |_| guesses.next().map(str::to_owned),
+        );
+
+        /* a game that was quit is not counted */
+        assert_eq!(player.games_played(), 0);
+    }
+
+    #[test]
+    fn practice_game_not_counted() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            practice: true,
+            hard_mode: true,
+            ..GameOptions::default()
+        };
+
+        /* the second guess breaks hard mode */
+        let mut guesses = ["CRATE", "BLIND", "TRACE"].into_iter();
+        run_with(&answer, None, &mut player, &dictionary, &options, |_| {
+            guesses.next().map(str::to_owned)
+        });
+
+        assert_eq!(player, PlayerInfo::new("player"));
+    }
+
+    #[test]
+    fn practice_game_is_not_saved() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let username = std::env::temp_dir()
+            .join(format!("wordle-rs-{}-practice", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let mut player = PlayerInfo::new(username.as_str());
+        let options = GameOptions {
+            practice: true,
+            allow_resume: true,
+            quiet: true,
+            ..GameOptions::default()
+        };
+
+        /* quitting a practice game does not save it */
+        let filename = saved_game::saved_game_filename(&username);
+        let mut guesses = ["CRATE"].into_iter();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |_| {
+            guesses.next().map(str::to_owned)
+        });
+        assert_eq!(result, None);
+        assert!(!std::path::Path::new(&filename).exists());
+
+        /* finishing a practice game keeps a saved game that is counted */
+        fs::write(&filename, "saved game").unwrap();
+        let mut guesses = ["CRATE", "TRACE"].into_iter();
+        run_with(&answer, None, &mut player, &dictionary, &options, |_| {
+            guesses.next().map(str::to_owned)
+        })
+        .unwrap();
+        assert_eq!(fs::read_to_string(&filename).unwrap(), "saved game");
+        fs::remove_file(&filename).unwrap();
+
+        assert_eq!(player, PlayerInfo::new(username.as_str()));
+    }
+
+    #[test]
+    fn quit_keyword_aborts_game() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            quiet: true,
+            ..GameOptions::default()
+        };
+
+        let mut input = io::Cursor::new("CRATE\n :q \nTRACE\n");
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |turn| {
+            turns.push(turn);
+            read_guess_from(turn, &options, &mut input, &mut io::sink())
+        });
+
+        assert_eq!(result, None);
+        assert_eq!(turns, [1, 2]);
+        assert_eq!(player, PlayerInfo::new("player"));
+    }
+
+    #[test]
+    fn end_of_input_aborts_game() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            quiet: true,
+            ..GameOptions::default()
+        };
+
+        /* an invalid guess, then the input runs out */
+        let mut input = io::Cursor::new("ZZZZZ\n");
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |turn| {
+            turns.push(turn);
+            read_guess_from(turn, &options, &mut input, &mut io::sink())
+        });
+
+        assert_eq!(result, None);
+        assert_eq!(turns, [1, 1]);
+        assert_eq!(player, PlayerInfo::new("player"));
+    }
+
+    #[test]
+    fn quit_keyword_deletes_saved_game() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let username = std::env::temp_dir()
+            .join(format!("wordle-rs-{}-quit", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let mut player = PlayerInfo::new(username.as_str());
+        let options = GameOptions {
+            allow_resume: true,
+            quiet: true,
+            ..GameOptions::default()
+        };
+        let filename = saved_game::saved_game_filename(&username);
+
+        /* running out of input keeps the game so it can be resumed */
+        let mut guesses = ["CRATE"].into_iter();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |_| {
+            guesses.next().map(str::to_owned)
+        });
+        assert_eq!(result, None);
+        assert!(std::path::Path::new(&filename).exists());
+
+        let mut guesses = ["BLIND", ":q"].into_iter();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |_| {
+            guesses.next().map(str::to_owned)
+        });
+        assert_eq!(result, None);
+        assert!(!std::path::Path::new(&filename).exists());
+    }
+
+    #[test]
+    fn multibyte_guesses_are_rejected() {
+        let dictionary: HashSet<_> = ["CRATE", "TRACE"].into_iter().map(str::to_owned).collect();
+        assert_eq!(
+            validate_guess("CAFÉS", &dictionary, WORD_LEN),
+            Err(GuessError::NotAlphabetic)
+        );
+        /* five bytes, but only three letters */
+        assert_eq!(
+            validate_guess("ÉÉA", &dictionary, WORD_LEN),
+            Err(GuessError::WrongLength {
+                expected: WORD_LEN,
+                found: 3
+            })
+        );
+
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            quiet: true,
+            ..GameOptions::default()
+        };
+        let mut guesses = ["CAFÉS", "ÉÉA", "TRACE"].into_iter();
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |turn| {
+            turns.push(turn);
+            guesses.next().map(str::to_owned)
+        });
+
+        assert_eq!(turns, [1, 1, 1]);
+        assert_eq!(result.unwrap().num_guesses, Some(1));
+    }
+
+    #[test]
+    fn custom_word_list_is_used() {
+        /// Accepts only the words in a fixed list.
+        struct Whitelist;
+
+        impl WordList for Whitelist {
+            fn is_valid(&self, word: &str) -> bool {
+                ["BLIND", "TRACE"].contains(&word)
+            }
         }
-        None => {
-            player.add_lost_word(answer.get_word().to_owned());
-            write!(lock, "Too bad! ").unwrap();
+
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            quiet: true,
+            ..GameOptions::default()
+        };
+        let mut guesses = ["CRATE", "BLIND", "TRACE"].into_iter();
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &Whitelist, &options, |turn| {
+            turns.push(turn);
+            guesses.next().map(str::to_owned)
+        });
+
+        /* CRATE is not in the list, so it does not use up a turn */
+        assert_eq!(turns, [1, 1, 2]);
+        assert_eq!(result.unwrap().num_guesses, Some(2));
+
+        let words: &[&str] = &["TRACE"];
+        assert_eq!(validate_guess("TRACE", &words, WORD_LEN), Ok(()));
+        assert_eq!(
+            validate_guess("BLIND", &words, WORD_LEN),
+            Err(GuessError::NotInDictionary)
+        );
+    }
+
+    #[test]
+    fn unlisted_word_list_has_no_suggestions() {
+        /// Accepts any word, without being able to list them.
+        struct AnyWord;
+
+        impl WordList for AnyWord {
+            fn is_valid(&self, _: &str) -> bool {
+                true
+            }
         }
-        /* user likely quit the program with Ctrl-C */
-        Some(_) => return,
+
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            allow_suggestions: true,
+            ..GameOptions::default()
+        };
+        let context = TurnContext::new(None, &AnyWord, &options, "player");
+        assert!(!context.can_suggest());
+
+        let mut guesses = [":suggest", "CRATE"].into_iter().map(str::to_owned);
+        let mut out = Vec::new();
+        let mut game = Game::new(answer, &AnyWord);
+        let outcome = play_turn(
+            1,
+            &context,
+            &mut game,
+            &mut player,
+            &mut |_| guesses.next(),
+            &mut out,
+        );
+        assert_eq!(outcome, TurnOutcome::Played);
+        assert_eq!(game.history().len(), 1);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("Error: suggestions are not available"));
+    }
+
+    #[test]
+    fn allowed_guess_is_not_an_answer() {
+        let dictionary: HashSet<_> = ["TRACE"].into_iter().map(str::to_owned).collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            quiet: true,
+            allowed_guesses: ["AAHED"].into_iter().map(str::to_owned).collect(),
+            ..GameOptions::default()
+        };
+        let mut guesses = ["CRATE", "AAHED", "TRACE"].into_iter();
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |turn| {
+            turns.push(turn);
+            guesses.next().map(str::to_owned)
+        });
+
+        /* AAHED is an allowed guess, but CRATE is in neither list */
+        assert_eq!(turns, [1, 1, 2]);
+        assert_eq!(result.unwrap().history[0].0, "AAHED");
+        /* answers are only chosen from the dictionary */
+        assert_eq!(player.get_random_word(&dictionary), None);
+    }
+
+    #[test]
+    fn allowed_guess_is_not_suggested() {
+        let dictionary: HashSet<_> = ["TRACE"].into_iter().map(str::to_owned).collect();
+        let options = GameOptions {
+            allow_suggestions: true,
+            allowed_guesses: ["AAHED"].into_iter().map(str::to_owned).collect(),
+            ..GameOptions::default()
+        };
+        let words = AnswersAndGuesses {
+            answers: &dictionary,
+            guesses: &options.allowed_guesses,
+        };
+
+        let context = TurnContext::new(None, &dictionary, &options, "player");
+        assert!(words.is_valid("AAHED"));
+        assert_eq!(context.sorted_dictionary, ["TRACE"]);
+    }
+
+    #[test]
+    fn alias_guess_wins() {
+        let dictionary: HashSet<_> = ["GRAYS", "GREYS"].into_iter().map(str::to_owned).collect();
+        let answer = WordleAnswer::new("GREYS");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            aliases: HashMap::from([(
+                String::from("GREYS"),
+                HashSet::from([String::from("GRAYS")]),
+            )]),
+            ..GameOptions::default()
+        };
+
+        let mut guesses = ["GRAYS"].into_iter();
+        run_with(&answer, None, &mut player, &dictionary, &options, |_| {
+            guesses.next().map(str::to_owned)
+        });
+
+        assert_eq!(player.games_won(), 1);
+        assert_eq!(player.stats().distribution, [1, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn resumed_game_continues() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let mut player = PlayerInfo::new("player");
+        let saved = SavedGame {
+            answer: String::from("TRACE"),
+            puzzle_id: Some(42),
+            guesses: vec![String::from("BLIND"), String::from("CRATE")],
+            num_hints: 0,
+        };
+
+        let mut turns = Vec::new();
+        let result = resume_with(
+            &saved,
+            &mut player,
+            &dictionary,
+            &GameOptions::default(),
+            |turn| {
+                turns.push(turn);
+                Some(String::from("TRACE"))
+            },
+        )
+        .unwrap();
+
+        assert_eq!(turns, [3]);
+        assert_eq!(result.num_guesses, Some(3));
+        assert_eq!(result.history.len(), 3);
+        assert_eq!(player.stats().distribution, [0, 0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn summary_of_won_and_lost_games() {
+        let won = GameResult {
+            answer: String::from("TRACE"),
+            history: vec![
+                (String::from("CRATE"), guess_result![Y G G Y G]),
+                (String::from("TRACE"), guess_result![G G G G G]),
+            ],
+            num_guesses: Some(2),
+        };
+        assert_eq!(
+            game_summary_line(&won, Some(Duration::from_secs(42))),
+            "Turns: 2/6 | Time: 42s | Result: Won"
+        );
+
+        let lost = GameResult {
+            answer: String::from("TRACE"),
+            history: vec![(String::from("BLIND"), guess_result![X X X X X]); MAX_GUESSES],
+            num_guesses: None,
+        };
+        assert_eq!(game_summary_line(&lost, None), "Turns: 6/6 | Result: Lost");
+
+        /* a hint used up the first turn of each game */
+        let won_with_hint = GameResult {
+            history: won.history[1..].to_vec(),
+            ..won
+        };
+        assert_eq!(
+            game_summary_line(&won_with_hint, None),
+            "Turns: 2/6 | Result: Won"
+        );
+        let lost_with_hint = GameResult {
+            history: lost.history[1..].to_vec(),
+            ..lost
+        };
+        assert_eq!(
+            game_summary_line(&lost_with_hint, None),
+            "Turns: 6/6 | Result: Lost"
+        );
+    }
+
+    #[test]
+    fn result_line_format() {
+        let won = GameResult {
+            answer: String::from("TRACE"),
+            history: vec![
+                (String::from("BLIND"), guess_result![X X X X X]),
+                (String::from("CRATE"), guess_result![Y G G Y G]),
+                (String::from("TRACE"), guess_result![G G G G G]),
+            ],
+            num_guesses: Some(3),
+        };
+        assert_eq!(result_line(&won), "RESULT WON 3 TRACE");
+
+        let lost = GameResult {
+            answer: String::from("TRACE"),
+            history: vec![(String::from("BLIND"), guess_result![X X X X X]); MAX_GUESSES],
+            num_guesses: None,
+        };
+        assert_eq!(result_line(&lost), "RESULT LOST 6 TRACE");
+
+        /* a turn used up by a hint is counted */
+        let hinted = GameResult {
+            answer: String::from("TRACE"),
+            history: vec![
+                (String::from("CRATE"), guess_result![Y G G Y G]),
+                (String::from("TRACE"), guess_result![G G G G G]),
+            ],
+            num_guesses: Some(3),
+        };
+        assert_eq!(result_line(&hinted), "RESULT WON 3 TRACE");
+    }
+
+    #[test]
+    fn quiet_game_only_prints_result() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            quiet: true,
+            allow_hints: true,
+            ..GameOptions::default()
+        };
+
+        let mut guesses = ["XYZZY", ":hint", "CRATE", "TRACE"]
+            .into_iter()
+            .map(str::to_owned);
+        let mut out = Vec::new();
+        let result = play(
+            &answer,
+            None,
+            &mut player,
+            &dictionary,
+            &options,
+            |_| guesses.next(),
+            &[],
+            0,
+            &mut out,
+        );
+
+        assert_eq!(result.unwrap().num_guesses, Some(3));
+        assert_eq!(String::from_utf8(out).unwrap(), "RESULT WON 3 TRACE\n");
+    }
+
+    #[test]
+    fn repeated_guess_does_not_use_turn() {
+        let dictionary: HashSet<_> = ["CRATE", "TRACE"].into_iter().map(str::to_owned).collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            warn_repeat_guess: true,
+            ..Default::default()
+        };
+
+        let mut guesses = ["CRATE", "CRATE", "TRACE"].into_iter();
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |turn| {
+            turns.push(turn);
+            guesses.next().map(str::to_owned)
+        })
+        .unwrap();
+
+        assert_eq!(turns, [1, 2, 2]);
+        assert_eq!(result.num_guesses, Some(2));
+        assert_eq!(result.history.len(), 2);
+    }
+
+    #[test]
+    fn unvalidated_guess_is_accepted() {
+        let dictionary: HashSet<_> = ["CRATE", "TRACE"].into_iter().map(str::to_owned).collect();
+        let answer = WordleAnswer::new("TRACE");
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            validate_guesses: false,
+            ..Default::default()
+        };
+
+        /* the wrong length and non-letters are still rejected */
+        let mut guesses = ["ZZZZ", "ZZZZ1", "ZZZZZ", "TRACE"].into_iter();
+        let mut turns = Vec::new();
+        let result = run_with(&answer, None, &mut player, &dictionary, &options, |turn| {
+            turns.push(turn);
+            guesses.next().map(str::to_owned)
+        })
+        .unwrap();
+
+        assert_eq!(turns, [1, 1, 1, 2]);
+        assert_eq!(result.history[0].0, "ZZZZZ");
+        assert_eq!(result.num_guesses, Some(2));
+    }
+
+    #[test]
+    fn turn_outcomes() {
+        let dictionary: HashSet<_> = ["CRATE", "TRACE"].into_iter().map(str::to_owned).collect();
+        let mut player = PlayerInfo::new("player");
+        let options = GameOptions {
+            allow_hints: true,
+            ..Default::default()
+        };
+        let context = TurnContext::new(None, &dictionary, &options, "player");
+        let mut out = io::sink();
+
+        let mut play = |turn, game: &mut Game<_, _>, guess: Option<&str>| {
+            let mut guesses = guess.map(str::to_owned).into_iter();
+            play_turn(
+                turn,
+                &context,
+                game,
+                &mut player,
+                &mut |_| guesses.next(),
+                &mut out,
+            )
+        };
+
+        let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+        assert_eq!(play(1, &mut game, Some("CRATE")), TurnOutcome::Played);
+        assert_eq!(play(2, &mut game, Some(":hint")), TurnOutcome::Played);
+        assert_eq!(play(3, &mut game, Some("TRACE")), TurnOutcome::Played);
+        assert_eq!(game.is_over(), Some(GameOutcome::Won(3)));
+        assert_eq!(game.history().len(), 2);
+        assert_eq!(game.num_hints(), 1);
+
+        let mut game = Game::with_max_guesses(WordleAnswer::new("TRACE"), &dictionary, 1);
+        assert_eq!(play(1, &mut game, Some("CRATE")), TurnOutcome::Played);
+        assert_eq!(game.is_over(), Some(GameOutcome::Lost));
+
+        let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+        assert_eq!(play(1, &mut game, None), TurnOutcome::Quit);
+        /* an invalid guess asks again, so quitting after it quits the turn */
+        assert_eq!(play(1, &mut game, Some("ZZZZZ")), TurnOutcome::Quit);
+        assert!(game.history().is_empty());
+    }
+
+    #[test]
+    fn custom_prompt_is_used() {
+        let options = GameOptions {
+            prompt: String::from("Guess {n} of {n}? "),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let guess = read_guess_from(4, &options, &mut io::Cursor::new("trace\n"), &mut out);
+        assert_eq!(guess.as_deref(), Some("trace\n"));
+        assert_eq!(String::from_utf8(out).unwrap(), "Guess 4 of 4? ");
+
+        let mut out = Vec::new();
+        read_guess_from(
+            1,
+            &GameOptions::default(),
+            &mut io::Cursor::new(""),
+            &mut out,
+        );
+        assert_eq!(String::from_utf8(out).unwrap(), "[1] ");
+
+        let options = GameOptions {
+            quiet: true,
+            ..options
+        };
+        let mut out = Vec::new();
+        read_guess_from(1, &options, &mut io::Cursor::new(""), &mut out);
+        assert!(out.is_empty());
     }
-    writeln!(lock, "The word was: {}", answer.get_word()).unwrap();
-    writeln!(lock).unwrap();
 }
@@ -6,20 +6,35 @@
 use std::{
     borrow::Borrow,
     collections::{BTreeSet, HashSet},
-    io::{self, stdin, Write},
+    fs,
+    io::{self, stdin, BufRead, Write},
+    path::Path,
 };
 
-use crate::{console_app::game, players::PlayerInfo, WordleAnswer};
+use crate::{
+    console_app::{
+        game::{self, GameOptions},
+        saved_game::{self, SavedGame},
+    },
+    normalize::AsciiUppercase,
+    players::{self, Achievement, PlayerInfo},
+    WordleAnswer, WORD_LEN,
+};
 
 /// Possible states of the main Wordle program.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///
+/// States that need a logged in user carry that user's
+/// information, so the user always exists in those states.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProgramState {
     /// Request the user's login information
     LogIn,
-    /// Run the main menu
-    MainMenu,
+    /// Run the main menu for the current user
+    MainMenu(PlayerInfo<String>),
     /// Delete the current user
-    DeleteUser,
+    DeleteUser(PlayerInfo<String>),
+    /// Show the leaderboard of all users
+    ViewLeaderboard(PlayerInfo<String>),
     /// Exit the program
     Exit,
 }
@@ -35,6 +50,14 @@ enum UserSelection {
     LogOff,
     /// Delete the current user
     DeleteUser,
+    /// Play a puzzle shared by another player
+    PlaySharedPuzzle,
+    /// Practice a chosen word without affecting statistics
+    PracticeWord,
+    /// Play games of Wordle until the user quits
+    KeepPlaying,
+    /// View the leaderboard of all users
+    ViewLeaderboard,
 }
 
 impl TryFrom<isize> for UserSelection {
@@ -46,6 +69,10 @@ impl TryFrom<isize> for UserSelection {
             x if x == Self::ViewStats as isize => Ok(Self::ViewStats),
             x if x == Self::LogOff as isize => Ok(Self::LogOff),
             x if x == Self::DeleteUser as isize => Ok(Self::DeleteUser),
+            x if x == Self::PlaySharedPuzzle as isize => Ok(Self::PlaySharedPuzzle),
+            x if x == Self::PracticeWord as isize => Ok(Self::PracticeWord),
+            x if x == Self::KeepPlaying as isize => Ok(Self::KeepPlaying),
+            x if x == Self::ViewLeaderboard as isize => Ok(Self::ViewLeaderboard),
             _ => Err(()),
         }
     }
@@ -59,7 +86,12 @@ impl TryFrom<isize> for UserSelection {
 /// about the player.
 ///
 /// If the user does not yet exist in the given databse,
-/// they will be added to it.
+/// they will be added to it. The user is also moved to the
+/// front of the list of recent users.
+///
+/// If the user's database file is corrupt, the user is asked
+/// whether to start over. If so, the corrupt file is backed
+/// up and a fresh player is created.
 ///
 /// # Panics
 ///
@@ -76,47 +108,292 @@ impl TryFrom<isize> for UserSelection {
 /// # }
 /// let mut usernames: BTreeSet<String> =
 ///     read_usernames("usernames.txt");
+/// let mut recent_users: Vec<String> = Vec::new();
 ///
-/// let player_info = main_menu::request_user_login(&mut usernames);
+/// let player_info =
+///     main_menu::request_user_login(&mut usernames, &mut recent_users);
 /// match player_info {
 ///     Some(player) => { /* run program */ },
 ///     None => { /* exit program */ }
 /// }
 /// ```
+#[inline]
 #[must_use]
-pub fn request_user_login(usernames: &mut BTreeSet<String>) -> Option<PlayerInfo<String>> {
-    let username = match request_username(usernames) {
-        Some(username) => username,
-        /* user requested to exit the game */
-        None => return None,
-    };
+pub fn request_user_login(
+    usernames: &mut BTreeSet<String>,
+    recent_users: &mut Vec<String>,
+) -> Option<PlayerInfo<String>> {
+    request_user_login_with(usernames, recent_users, &GameOptions::default())
+}
+
+/// Requests a user to enter their login information,
+/// following the given game options.
+///
+/// This works the same as [`request_user_login`], except that
+/// no prompts or greeting are shown if the options are quiet,
+/// and the existing users are listed with the most recent users
+/// first if the options sort users by recency.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::collections::BTreeSet;
+/// # use wordle::console_app::{game::GameOptions, main_menu};
+/// let mut usernames = BTreeSet::new();
+/// let mut recent_users = Vec::new();
+/// let options = GameOptions {
+///     quiet: true,
+///     ..GameOptions::default()
+/// };
+///
+/// let player_info =
+///     main_menu::request_user_login_with(&mut usernames, &mut recent_users, &options);
+/// ```
+#[must_use]
+pub fn request_user_login_with(
+    usernames: &mut BTreeSet<String>,
+    recent_users: &mut Vec<String>,
+    options: &GameOptions,
+) -> Option<PlayerInfo<String>> {
+    /* if there is no username, the user requested to exit the game */
+    let username = request_username(usernames, recent_users, options)?;
+    log_in_with(username, usernames, recent_users, options)
+}
 
-    let player_info = PlayerInfo::from_file(&(username.clone() + ".txt"));
+/// Logs in a user without asking for their username.
+///
+/// If the user does not yet exist in the given database,
+/// they will be added to it. The user is also moved to the
+/// front of the list of recent users. The username should
+/// already be normalized with [`normalize_username`].
+///
+/// If the user's database file is corrupt, the user is asked
+/// whether to start over. If so, the corrupt file is backed
+/// up and a fresh player is created. Otherwise, or if the file
+/// could not be read, this function returns None.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::collections::BTreeSet;
+/// # use wordle::console_app::main_menu;
+/// let mut usernames = BTreeSet::new();
+/// let mut recent_users = Vec::new();
+///
+/// let username = main_menu::normalize_username("User").unwrap();
+/// let player_info = main_menu::log_in(username, &mut usernames, &mut recent_users);
+/// ```
+#[inline]
+#[must_use]
+pub fn log_in(
+    username: String,
+    usernames: &mut BTreeSet<String>,
+    recent_users: &mut Vec<String>,
+) -> Option<PlayerInfo<String>> {
+    log_in_with(username, usernames, recent_users, &GameOptions::default())
+}
+
+/// Logs in a user without asking for their username,
+/// following the given game options.
+///
+/// This works the same as [`log_in`], except that the user
+/// is not greeted if the options are quiet. Errors are still
+/// shown in quiet mode, but the user is not offered to start
+/// over if their database file is corrupt, so this function
+/// returns None without waiting for input.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::collections::BTreeSet;
+/// # use wordle::console_app::{game::GameOptions, main_menu};
+/// let mut usernames = BTreeSet::new();
+/// let mut recent_users = Vec::new();
+/// let options = GameOptions {
+///     quiet: true,
+///     ..GameOptions::default()
+/// };
+///
+/// let username = main_menu::normalize_username("User").unwrap();
+/// let player_info =
+///     main_menu::log_in_with(username, &mut usernames, &mut recent_users, &options);
+/// ```
+#[must_use]
+pub fn log_in_with(
+    username: String,
+    usernames: &mut BTreeSet<String>,
+    recent_users: &mut Vec<String>,
+    options: &GameOptions,
+) -> Option<PlayerInfo<String>> {
+    if !usernames.contains(&username) {
+        /* new user, add to database */
+        usernames.insert(username.clone());
+    }
+    log_recent_user(recent_users, &username);
+
+    let mut stdout = io::stdout();
+    let filename = username.clone() + ".txt";
+    let player_info = PlayerInfo::from_file(&filename);
+    /* errors are shown even in quiet mode */
     let player_info = match player_info {
         Ok(player_info) => player_info,
+        /* quiet mode runs without a user to answer, so do not offer to start over */
+        Err(msg) if msg.kind() == io::ErrorKind::InvalidData && options.quiet => {
+            writeln!(stdout, "{}", msg).unwrap();
+            return None;
+        }
+        /* the database file is corrupt, offer to start over */
+        Err(msg) if msg.kind() == io::ErrorKind::InvalidData => {
+            writeln!(stdout, "{}", msg).unwrap();
+            Some(recover_corrupt_player(
+                username.clone(),
+                &filename,
+                &mut stdin().lock(),
+                &mut stdout,
+            )?)
+        }
         /* error reading the database file */
         Err(msg) => {
-            println!("{}", msg);
+            writeln!(stdout, "{}", msg).unwrap();
             return None;
         }
     };
 
-    println!("Hello, {username}");
+    let mut out = quiet_output(&mut stdout, options.quiet);
+    writeln!(out, "Hello, {username}").unwrap();
 
     /* this might be a new user, create a fresh instance of PlayerInfo if so */
     let player_info = player_info.unwrap_or_else(|| PlayerInfo::new(username));
     Some(player_info)
 }
 
+/// Asks a user whether to start over after their database file
+/// was found to be corrupt.
+///
+/// If the user agrees, the corrupt file is renamed with a ".corrupt"
+/// extension and a fresh player is returned. This does not replace
+/// the ".bak" file written by a failed save, which may still hold
+/// the player's progress. Otherwise, or if the
+/// file could not be renamed, this function returns None.
+///
+/// # Panics
+///
+/// Panics if writing to `out` fails.
+fn recover_corrupt_player(
+    username: String,
+    filename: &str,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> Option<PlayerInfo<String>> {
+    write!(out, "Your save is corrupt. Start over? [y/N] ").unwrap();
+    out.flush().unwrap();
+
+    let mut user_confirmation = String::new();
+    match input.read_line(&mut user_confirmation) {
+        Ok(_) if user_confirmation.trim().eq_ignore_ascii_case("y") => {
+            /* keep the corrupt file in case the user wants to repair it */
+            if fs::rename(filename, filename.to_owned() + ".corrupt").is_err() {
+                writeln!(
+                    out,
+                    "Error: could not back up the corrupt user database file"
+                )
+                .unwrap();
+                return None;
+            }
+            Some(PlayerInfo::new(username))
+        }
+        _ => None,
+    }
+}
+
+/// Moves a user to the front of the list of recent users.
+///
+/// If the user is not yet in the list, they will be added to it.
+fn log_recent_user(recent_users: &mut Vec<String>, username: &str) {
+    recent_users.retain(|name| name != username);
+    recent_users.insert(0, username.to_owned());
+}
+
+/// Sorts a set of usernames by how recently each user logged in.
+///
+/// The most recent users are listed first, in the order they appear
+/// in the list of recent users. Any users who are not in that list
+/// are listed afterwards in alphabetical order.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use wordle::console_app::main_menu;
+/// let usernames = BTreeSet::from([
+///     String::from("alice"),
+///     String::from("bob"),
+///     String::from("carol"),
+/// ]);
+/// let recent_users = [String::from("carol")];
+///
+/// let sorted = main_menu::sort_by_recency(&usernames, &recent_users);
+/// assert_eq!(sorted, ["carol", "alice", "bob"]);
+/// ```
+#[must_use]
+pub fn sort_by_recency<'a>(
+    usernames: &'a BTreeSet<String>,
+    recent_users: &[String],
+) -> Vec<&'a str> {
+    let recent = recent_users
+        .iter()
+        .filter_map(|name| usernames.get(name))
+        .map(String::as_str);
+    let others = usernames
+        .iter()
+        .filter(|name| !recent_users.contains(name))
+        .map(String::as_str);
+    recent.chain(others).collect()
+}
+
+/// Lists the existing users in the order they are shown
+/// when asking for a username.
+///
+/// See [`request_username`].
+fn listed_usernames<'a>(
+    usernames: &'a BTreeSet<String>,
+    recent_users: &[String],
+    options: &GameOptions,
+) -> Vec<&'a str> {
+    if options.sort_users_by_recency {
+        sort_by_recency(usernames, recent_users)
+    } else {
+        usernames.iter().map(String::as_str).collect()
+    }
+}
+
 /// Requests a user to enter their username.
 ///
+/// The list of existing users is printed in alphabetical
+/// order, or with the most recent users first if the options
+/// sort users by recency. If the options are quiet, the list
+/// and the prompt are not printed.
+///
 /// The user may choose to quit the program (or forcibly
 /// quit using Ctrl-C), in which case this function returns
 /// None.
 ///
-/// If the user does not yet exist in the given database,
-/// they will be added to it.
-///
 /// # Panics
 ///
 /// Panics if writing to [`io::stdout`] fails.
@@ -126,69 +403,101 @@ pub fn request_user_login(usernames: &mut BTreeSet<String>) -> Option<PlayerInfo
 /// Basic usage:
 /// ```ignore
 /// # use std::collections::BTreeSet;
-/// # use wordle::console_app::main_menu;
+/// # use wordle::console_app::{game::GameOptions, main_menu};
 /// # fn read_usernames(filename: &str) -> BTreeSet<String> {
 /// # BTreeSet::new()
 /// # }
-/// let mut usernames: BTreeSet<String> =
+/// let usernames: BTreeSet<String> =
 ///     read_usernames("usernames.txt");
+/// let recent_users: Vec<String> = Vec::new();
 ///
-/// let username = main_menu::request_username(&mut usernames);
+/// let username =
+///     main_menu::request_username(&usernames, &recent_users, &GameOptions::default());
 /// match username {
 ///     Some(username) => { /* create player with username */ },
 ///     None => { /* exit program */ }
 /// }
 /// ```
 #[must_use]
-fn request_username(usernames: &mut BTreeSet<String>) -> Option<String> {
-    {
-        let mut lock = io::stdout().lock();
-        if !usernames.is_empty() {
-            writeln!(lock, "List of existing users:").unwrap();
-            for name in usernames.iter() {
-                writeln!(lock, "{name}").unwrap();
-            }
-            writeln!(lock).unwrap();
+fn request_username(
+    usernames: &BTreeSet<String>,
+    recent_users: &[String],
+    options: &GameOptions,
+) -> Option<String> {
+    let mut stdout = io::stdout();
+    let mut out = quiet_output(&mut stdout, options.quiet);
+    if !usernames.is_empty() {
+        writeln!(out, "List of existing users:").unwrap();
+        for name in listed_usernames(usernames, recent_users, options) {
+            writeln!(out, "{name}").unwrap();
         }
-
-        writeln!(lock, "Note: usernames are case-insensitive").unwrap();
-        writeln!(lock, "Type \":q\" to exit").unwrap();
-        write!(lock, "Username: ").unwrap();
-        lock.flush().unwrap();
+        writeln!(out).unwrap();
     }
 
-    let mut username = String::new();
-    if stdin().read_line(&mut username).is_err() {
-        /* user likely quit the program with Ctrl-C */
-        return None;
-    }
-    let username = username.trim().to_lowercase();
+    writeln!(out, "Note: usernames are case-insensitive").unwrap();
+    writeln!(out, "Type \":q\" to exit").unwrap();
 
-    if username == ":q" {
-        /* user wants to exit */
-        return None;
-    }
+    loop {
+        write!(out, "Username: ").unwrap();
+        out.flush().unwrap();
 
-    if !usernames.contains(&username) {
-        /* new user, add to database */
-        usernames.insert(username.clone());
+        let mut username = String::new();
+        match stdin().read_line(&mut username) {
+            /* user likely quit the program with Ctrl-C or closed the input */
+            Err(_) | Ok(0) => return None,
+            Ok(_) => {}
+        }
+
+        match normalize_username(&username) {
+            /* user wants to exit */
+            Some(username) if username == ":q" => return None,
+            Some(username) => return Some(username),
+            None => writeln!(out, "Error: username cannot be empty").unwrap(),
+        }
     }
+}
 
-    Some(username)
+/// Normalizes a username entered by the user.
+///
+/// Usernames are case-insensitive, so the username is trimmed
+/// and converted to lowercase. If the username is empty, this
+/// function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::console_app::main_menu;
+/// assert_eq!(main_menu::normalize_username(" User\n"), Some(String::from("user")));
+/// assert_eq!(main_menu::normalize_username("  "), None);
+/// ```
+#[must_use]
+pub fn normalize_username(username: &str) -> Option<String> {
+    let username = username.trim().to_lowercase();
+    if username.is_empty() {
+        None
+    } else {
+        Some(username)
+    }
 }
 
 /// Runs the Wordle main menu.
 ///
-/// The main menu gives the player four options:
+/// The main menu gives the player eight options:
 /// - Play a game of Wordle
+/// - Play a shared puzzle
+/// - Practice a word, which is not counted in their statistics
 /// - View their statistics
 /// - Log out
 /// - Delete their account
+/// - Keep playing games until they quit
+/// - View the leaderboard of all users
 ///
 /// This function lets the caller know what the next
 /// state of the program should be. For example, if
 /// the user has logged off, the main program should
-/// return to the login screen.
+/// return to the login screen. The player is given
+/// back in the next state if they are still logged in.
 ///
 /// # Panics
 ///
@@ -199,7 +508,7 @@ fn request_username(usernames: &mut BTreeSet<String>) -> Option<String> {
 /// Basic usage:
 /// ```no_run
 /// # use std::collections::{BTreeSet, HashSet};
-/// # use wordle::console_app::main_menu;
+/// # use wordle::console_app::{game::GameOptions, main_menu};
 /// # fn read_dictionary(filename: &str) -> HashSet<String> {
 /// # HashSet::new()
 /// # }
@@ -210,62 +519,119 @@ fn request_username(usernames: &mut BTreeSet<String>) -> Option<String> {
 ///     read_dictionary("dictionary.txt");
 /// let mut usernames: BTreeSet<String> =
 ///     read_usernames("usernames.txt");
+/// let mut recent_users: Vec<String> = Vec::new();
 ///
-/// let player_info = main_menu::request_user_login(&mut usernames);
-/// if let Some(mut player_info) = player_info {
-///     let next_state = main_menu::run(&mut player_info, &dictionary);
+/// let player_info =
+///     main_menu::request_user_login(&mut usernames, &mut recent_users);
+/// if let Some(player_info) = player_info {
+///     let next_state =
+///         main_menu::run(player_info, &dictionary, &GameOptions::default());
 /// }
 /// ```
 #[must_use]
 pub fn run(
-    current_player: &mut PlayerInfo<impl Borrow<str>>,
+    current_player: PlayerInfo<String>,
     dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    options: &GameOptions,
 ) -> ProgramState {
-    let user_selection = request_user_selection();
-    let user_selection = match user_selection {
-        Some(user_selection) => user_selection,
+    let user_selection = request_user_selection(options.quiet);
+    match user_selection {
+        Some(user_selection) => run_selection(user_selection, current_player, dictionary, options),
         /* user likely quit the program with Ctrl-C */
-        None => return ProgramState::Exit,
-    };
+        None => ProgramState::Exit,
+    }
+}
 
+/// Runs the main menu option the user selected.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+#[must_use]
+fn run_selection(
+    user_selection: UserSelection,
+    mut current_player: PlayerInfo<String>,
+    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    options: &GameOptions,
+) -> ProgramState {
+    let mut stdout = io::stdout();
+    let mut out = quiet_output(&mut stdout, options.quiet);
     match user_selection {
         UserSelection::PlayGame => {
-            /* run a game of Wordle */
-            if let Some(answer) = current_player.get_random_word(dictionary) {
-                let answer = WordleAnswer::new(answer);
-                game::run(&answer, current_player, dictionary);
-                /* print the player's statistics after the game ends */
-                println!("{}", current_player.get_stats());
-                /* save the user's new statistics to their database */
-                if current_player
-                    .write_to_file(&(current_player.get_username().to_owned() + ".txt"))
-                    .is_err()
-                {
-                    /* report that we could not write to the database, but do not exit */
-                    println!(
-                        "Error: could not write to user database file, progress has not been saved"
-                    );
-                }
+            if let Some((puzzle_id, answer)) = choose_answer(&current_player, dictionary) {
+                play_game(answer, puzzle_id, &mut current_player, dictionary, options);
             } else {
                 /* couldn't get a word, player has already played every word */
-                println!("There are no remaining words in the dictionary.");
+                writeln!(out, "There are no remaining words in the dictionary.").unwrap();
             }
-            ProgramState::MainMenu
+            ProgramState::MainMenu(current_player)
+        }
+        UserSelection::KeepPlaying => {
+            writeln!(out, "Type \":q\" instead of a guess to stop playing").unwrap();
+            keep_playing(&mut current_player, dictionary, options, |turn| {
+                game::read_guess(turn, options)
+            });
+            ProgramState::MainMenu(current_player)
+        }
+        UserSelection::PlaySharedPuzzle => {
+            if let Some(puzzle_id) = request_puzzle_id(&mut stdin().lock(), &mut out) {
+                match players::get_puzzle_word(dictionary, puzzle_id) {
+                    Some(answer) => {
+                        if current_player.has_played(answer) {
+                            writeln!(
+                                out,
+                                "You have already played this puzzle, so it will not be counted"
+                            )
+                            .unwrap();
+                        }
+                        let options = shared_puzzle_options(&current_player, answer, options);
+                        play_game(
+                            answer,
+                            Some(puzzle_id),
+                            &mut current_player,
+                            dictionary,
+                            &options,
+                        );
+                    }
+                    None => writeln!(out, "There are no words in the dictionary.").unwrap(),
+                }
+            }
+            ProgramState::MainMenu(current_player)
+        }
+        UserSelection::PracticeWord => {
+            let options = GameOptions {
+                practice: true,
+                ..options.clone()
+            };
+            /* keep practicing until the user leaves the prompt empty */
+            while let Some(answer) =
+                request_practice_word(dictionary, &mut stdin().lock(), &mut out)
+            {
+                let answer = WordleAnswer::normalized(&answer, &AsciiUppercase);
+                game::run(&answer, None, &mut current_player, dictionary, &options);
+            }
+            ProgramState::MainMenu(current_player)
         }
         UserSelection::ViewStats => {
             println!("{}", current_player.get_stats());
-            ProgramState::MainMenu
+            ProgramState::MainMenu(current_player)
+        }
+        UserSelection::ViewLeaderboard => {
+            /* the leaderboard needs every user, so it is shown by the caller */
+            ProgramState::ViewLeaderboard(current_player)
         }
         UserSelection::LogOff => {
             /* user is logged off, go back to login screen */
             ProgramState::LogIn
         }
         UserSelection::DeleteUser => {
-            print!(
+            write!(
+                out,
                 "Are you sure you would like to delete user: {} [y/N] ",
                 current_player.get_username()
-            );
-            io::stdout().flush().unwrap();
+            )
+            .unwrap();
+            out.flush().unwrap();
 
             let mut user_confirmation = String::new();
             match stdin().read_line(&mut user_confirmation) {
@@ -275,28 +641,435 @@ pub fn run(
                         user_confirmation.trim()
                     } == "y" =>
                 {
-                    println!();
-                    ProgramState::DeleteUser
+                    writeln!(out).unwrap();
+                    ProgramState::DeleteUser(current_player)
                 }
                 _ => {
-                    println!("Action aborted");
-                    ProgramState::MainMenu
+                    writeln!(out, "Action aborted").unwrap();
+                    ProgramState::MainMenu(current_player)
                 }
             }
         }
     }
 }
 
+/// Chooses the answer to a new game for a player.
+///
+/// A numbered puzzle is preferred so the player can share it.
+/// The answer is always a word the player has not yet played.
+/// If the player has played every word, this function returns None.
+fn choose_answer<'a>(
+    current_player: &PlayerInfo<impl Borrow<str>>,
+    dictionary: &'a HashSet<String, impl std::hash::BuildHasher>,
+) -> Option<(Option<u32>, &'a str)> {
+    current_player
+        .get_random_puzzle(dictionary)
+        .map(|(puzzle_id, answer)| (Some(puzzle_id), answer))
+        .or_else(|| {
+            current_player
+                .get_random_word(dictionary)
+                .map(|answer| (None, answer))
+        })
+}
+
+/// Gets the game options for playing a shared puzzle.
+///
+/// A puzzle whose word the player has already played is
+/// played as a practice game, so it is not counted again.
+fn shared_puzzle_options(
+    current_player: &PlayerInfo<impl Borrow<str>>,
+    answer: &str,
+    options: &GameOptions,
+) -> GameOptions {
+    GameOptions {
+        practice: options.practice || current_player.has_played(answer),
+        ..options.clone()
+    }
+}
+
+/// Runs games of Wordle one after another until the user quits.
+///
+/// Each guess is read using `read_guess`, and the user quits by
+/// entering ":q" instead of a guess, which leaves the current game
+/// unfinished. The player's statistics are shown and saved after each
+/// game. The games also stop once the player has played every word.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+fn keep_playing(
+    current_player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    options: &GameOptions,
+    mut read_guess: impl FnMut(usize) -> Option<String>,
+) {
+    while let Some((puzzle_id, answer)) = choose_answer(current_player, dictionary) {
+        let answer = WordleAnswer::normalized(answer, &AsciiUppercase);
+        let earned = current_player.achievements();
+        let result = game::run_with(
+            &answer,
+            puzzle_id,
+            current_player,
+            dictionary,
+            options,
+            &mut read_guess,
+        );
+        if result.is_none() {
+            /* user quit the game */
+            return;
+        }
+        finish_game(current_player, &earned, options);
+    }
+    let mut stdout = io::stdout();
+    let mut out = quiet_output(&mut stdout, options.quiet);
+    writeln!(out, "There are no remaining words in the dictionary.").unwrap();
+}
+
+/// Runs a game of Wordle and saves the player's new statistics.
+///
+/// After the game ends, the player's statistics are printed
+/// and saved to their database file. If the database file
+/// cannot be written, the statistics are saved to a backup
+/// file instead, and they are kept for the rest of the session
+/// so the next save can try again.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+fn play_game(
+    answer: &str,
+    puzzle_id: Option<u32>,
+    current_player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    options: &GameOptions,
+) {
+    /* run a game of Wordle */
+    let answer = WordleAnswer::normalized(answer, &AsciiUppercase);
+    let earned = current_player.achievements();
+    game::run(&answer, puzzle_id, current_player, dictionary, options);
+    finish_game(current_player, &earned, options);
+}
+
+/// Offers to resume a user's unfinished game, if they have one.
+///
+/// If the user declines, or the saved game is corrupt, the
+/// saved game is deleted. Nothing happens if resuming is
+/// disabled in the game options.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+pub fn offer_resume(
+    current_player: &mut PlayerInfo<impl Borrow<str>>,
+    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    options: &GameOptions,
+) {
+    if !options.allow_resume {
+        return;
+    }
+
+    let mut stdout = io::stdout();
+    let mut out = quiet_output(&mut stdout, options.quiet);
+    let filename = saved_game::saved_game_filename(current_player.get_username());
+    let saved = match SavedGame::from_file(&filename) {
+        Ok(Some(saved)) => saved,
+        /* no unfinished game */
+        Ok(None) => return,
+        Err(msg) => {
+            writeln!(out, "{msg}").unwrap();
+            _ = fs::remove_file(&filename);
+            return;
+        }
+    };
+
+    if confirm_resume(&mut stdin().lock(), &mut out) {
+        let earned = current_player.achievements();
+        if game::resume(&saved, current_player, dictionary, options).is_some() {
+            finish_game(current_player, &earned, options);
+        }
+    } else {
+        _ = fs::remove_file(&filename);
+    }
+}
+
+/// Asks a user whether to resume their unfinished game.
+///
+/// # Panics
+///
+/// Panics if writing to `out` fails.
+fn confirm_resume(input: &mut impl BufRead, out: &mut impl Write) -> bool {
+    write!(out, "You have an unfinished game. Resume? [y/N] ").unwrap();
+    out.flush().unwrap();
+
+    let mut user_confirmation = String::new();
+    input.read_line(&mut user_confirmation).is_ok()
+        && user_confirmation.trim().eq_ignore_ascii_case("y")
+}
+
+/// Shows a player's statistics after a game and saves them
+/// to the player's database file.
+///
+/// Any achievements the player has earned that are not in
+/// `earned`, the achievements from before the game, are shown
+/// along with the statistics. Nothing is shown in quiet mode,
+/// including any errors saving the statistics.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+fn finish_game(
+    current_player: &PlayerInfo<impl Borrow<str>>,
+    earned: &[Achievement],
+    options: &GameOptions,
+) {
+    let mut stdout = io::stdout();
+    let mut out = quiet_output(&mut stdout, options.quiet);
+    /* print the player's statistics after the game ends */
+    for achievement in current_player.achievements() {
+        if !earned.contains(&achievement) {
+            writeln!(out, "Achievement unlocked: {achievement}!").unwrap();
+        }
+    }
+    writeln!(out, "{}", current_player.get_stats()).unwrap();
+    /* save the user's new statistics to their database */
+    let filename = current_player.get_username().to_owned() + ".txt";
+    match save_player(current_player, &filename) {
+        Ok(saved_filename) if saved_filename != filename => {
+            writeln!(
+                out,
+                "Warning: could not write to user database file {filename}"
+            )
+            .unwrap();
+            writeln!(out, "Progress was saved to {saved_filename} instead").unwrap();
+            writeln!(
+                out,
+                "Rename it to {filename} to keep your progress if the program is closed"
+            )
+            .unwrap();
+        }
+        Ok(_) => {}
+        Err(_) => {
+            /* report that we could not write to the database, but do not exit */
+            writeln!(
+                out,
+                "Error: could not write to user database file, progress has not been saved"
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "Your progress is kept for this session and will be saved after your next game"
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Saves a player's statistics to their database file.
+///
+/// If the database file cannot be written, the statistics are
+/// written to a backup file with ".bak" added to its name.
+/// On success, the name of the file that was written is returned.
+///
+/// # Errors
+///
+/// This function will return an error if writing to both the
+/// database file and the backup file fails.
+fn save_player(player: &PlayerInfo<impl Borrow<str>>, filename: &str) -> io::Result<String> {
+    match player.write_to_file(filename) {
+        Ok(()) => Ok(filename.to_owned()),
+        Err(_) => {
+            let backup_filename = filename.to_owned() + ".bak";
+            player.write_to_file(&backup_filename)?;
+            Ok(backup_filename)
+        }
+    }
+}
+
+/// A player's row in the leaderboard.
+///
+/// See [`build_leaderboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardRow {
+    /// Username of the player
+    pub username: String,
+    /// Maximum win streak of the player
+    pub max_win_streak: usize,
+    /// Percentage of games won, rounded to the nearest whole number
+    pub win_rate: u32,
+}
+
+/// Builds the leaderboard of all users from their database files.
+///
+/// The rows are sorted by maximum win streak, then by win rate,
+/// and then by username. Users whose database file is missing
+/// or could not be read are left out.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::collections::BTreeSet;
+/// # use wordle::console_app::main_menu;
+/// let usernames = BTreeSet::from([String::from("alice"), String::from("bob")]);
+/// for row in main_menu::build_leaderboard(&usernames) {
+///     println!("{}: {}", row.username, row.max_win_streak);
+/// }
+/// ```
+#[must_use]
+pub fn build_leaderboard(usernames: &BTreeSet<String>) -> Vec<LeaderboardRow> {
+    let players = players::load_players_parallel(usernames, Path::new("."))
+        .into_iter()
+        .filter_map(|(_, player)| player.ok().flatten());
+    leaderboard_rows(players)
+}
+
+/// Builds the leaderboard rows for the given players.
+///
+/// The rows are sorted the same way as [`build_leaderboard`].
+fn leaderboard_rows(
+    players: impl IntoIterator<Item = PlayerInfo<impl Borrow<str>>>,
+) -> Vec<LeaderboardRow> {
+    let mut rows: Vec<_> = players
+        .into_iter()
+        .map(|player| LeaderboardRow {
+            username: player.get_username().to_owned(),
+            max_win_streak: player.get_max_win_streak(),
+            win_rate: player.stats().win_rate,
+        })
+        .collect();
+    rows.sort_unstable_by(|a, b| {
+        b.max_win_streak
+            .cmp(&a.max_win_streak)
+            .then(b.win_rate.cmp(&a.win_rate))
+            .then_with(|| a.username.cmp(&b.username))
+    });
+    rows
+}
+
+/// Prints the leaderboard of all users.
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+pub fn show_leaderboard(usernames: &BTreeSet<String>) {
+    let rows = build_leaderboard(usernames);
+    if rows.is_empty() {
+        println!("There are no players with saved statistics.");
+        return;
+    }
+
+    let mut lock = io::stdout().lock();
+    writeln!(lock, "Leaderboard:").unwrap();
+    for (rank, row) in rows.iter().enumerate() {
+        writeln!(
+            lock,
+            "{}. {}: max streak {}, win rate {}%",
+            rank + 1,
+            row.username,
+            row.max_win_streak,
+            row.win_rate
+        )
+        .unwrap();
+    }
+}
+
+/// Gets the writer for the prompts and messages of the main menu.
+///
+/// Only the results of each game are shown in quiet mode, so
+/// everything written to the returned writer is then discarded.
+pub(super) fn quiet_output<'a>(out: &'a mut dyn Write, quiet: bool) -> Box<dyn Write + 'a> {
+    if quiet {
+        Box::new(io::sink())
+    } else {
+        Box::new(out)
+    }
+}
+
+/// Requests a user to input the number of a shared puzzle.
+///
+/// The user can cancel by entering nothing, or terminate the
+/// program early using Ctrl-C, in which case this function
+/// returns None.
+///
+/// # Panics
+///
+/// Panics if writing to `out` fails.
+#[must_use]
+fn request_puzzle_id(input: &mut impl BufRead, out: &mut impl Write) -> Option<u32> {
+    loop {
+        write!(out, "Puzzle number (leave empty to cancel): ").unwrap();
+        out.flush().unwrap();
+
+        let mut puzzle_id_str = String::new();
+        if input.read_line(&mut puzzle_id_str).is_err() {
+            /* user likely quit the program with Ctrl-C */
+            return None;
+        }
+
+        let puzzle_id_str = puzzle_id_str.trim().trim_start_matches('#');
+        if puzzle_id_str.is_empty() {
+            return None;
+        }
+        match puzzle_id_str.parse() {
+            Ok(puzzle_id) => return Some(puzzle_id),
+            Err(_) => writeln!(out, "Error: invalid puzzle number").unwrap(),
+        }
+    }
+}
+
+/// Requests a user to input a word to practice.
+///
+/// The word must be in the dictionary. The user can cancel
+/// by entering nothing, or terminate the program early using
+/// Ctrl-C, in which case this function returns None.
+///
+/// # Panics
+///
+/// Panics if writing to `out` fails.
+#[must_use]
+fn request_practice_word(
+    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> Option<String> {
+    loop {
+        write!(out, "Word to practice (leave empty to cancel): ").unwrap();
+        out.flush().unwrap();
+
+        let mut word = String::new();
+        if input.read_line(&mut word).is_err() {
+            /* user likely quit the program with Ctrl-C */
+            return None;
+        }
+
+        let word = game::normalize_guess(&word);
+        if word.is_empty() {
+            return None;
+        } else if word.chars().count() != WORD_LEN {
+            writeln!(out, "Error: word must be {WORD_LEN} letters").unwrap();
+        } else if !dictionary.contains(&word) {
+            writeln!(out, "Error: word must be in the dictionary").unwrap();
+        } else {
+            return Some(word);
+        }
+    }
+}
+
 /// Requests a user to input their selection.
 ///
-/// This function gives the player four options:
+/// This function gives the player eight options:
 /// - Play a game of Wordle
+/// - Play a shared puzzle
+/// - Practice a word
 /// - View their statistics
 /// - Log out
 /// - Delete their account
+/// - Keep playing games until they quit
+/// - View the leaderboard
 ///
-/// The user can terminate the program early using Ctrl-C,
-/// in which case this function returns None.
+/// If `quiet` is true, the options and the prompt are not
+/// printed. The user can terminate the program early using
+/// Ctrl-C, in which case this function returns None.
 ///
 /// # Panics
 ///
@@ -307,31 +1080,35 @@ pub fn run(
 /// Basic usage:
 /// ```ignore
 /// # use wordle::console_app::main_menu;
-/// let user_selection = main_menu::request_user_selection();
+/// let user_selection = main_menu::request_user_selection(false);
 /// match (user_selection) {
 ///     Some(user_selection) => { /* run selection */ },
 ///     None => { /* exit program */ }
 /// }
 /// ```
 #[must_use]
-fn request_user_selection() -> Option<UserSelection> {
-    {
-        let mut lock = io::stdout().lock();
-        writeln!(lock).unwrap();
-        writeln!(lock, "[1] Play a game of Wordle").unwrap();
-        writeln!(lock, "[2] View player statistics").unwrap();
-        writeln!(lock, "[3] Log off").unwrap();
-        writeln!(lock, "[4] Delete user").unwrap();
-    }
+fn request_user_selection(quiet: bool) -> Option<UserSelection> {
+    let mut stdout = io::stdout();
+    let mut out = quiet_output(&mut stdout, quiet);
+    writeln!(out).unwrap();
+    writeln!(out, "[1] Play a game of Wordle").unwrap();
+    writeln!(out, "[2] View player statistics").unwrap();
+    writeln!(out, "[3] Log off").unwrap();
+    writeln!(out, "[4] Delete user").unwrap();
+    writeln!(out, "[5] Play a shared puzzle").unwrap();
+    writeln!(out, "[6] Practice a word").unwrap();
+    writeln!(out, "[7] Keep playing").unwrap();
+    writeln!(out, "[8] View leaderboard").unwrap();
 
     let user_selection = loop {
-        print!("Selection: ");
-        io::stdout().flush().unwrap();
+        write!(out, "Selection: ").unwrap();
+        out.flush().unwrap();
 
         let mut selection_str = String::new();
-        if stdin().read_line(&mut selection_str).is_err() {
-            /* user likely quit the program with Ctrl-C */
-            break None;
+        match stdin().read_line(&mut selection_str) {
+            /* user likely quit the program with Ctrl-C or closed the input */
+            Err(_) | Ok(0) => break None,
+            Ok(_) => {}
         }
 
         let selection = selection_str.trim().parse::<isize>();
@@ -343,15 +1120,381 @@ fn request_user_selection() -> Option<UserSelection> {
                     /* valid selection, stop the read loop */
                     Some(_) => break user_selection,
                     /* selection out of range */
-                    None => println!("Error: invalid selection"),
+                    None => writeln!(out, "Error: invalid selection").unwrap(),
                 }
             }
             Err(_) => {
-                println!("Error: selection must be an integer");
+                writeln!(out, "Error: selection must be an integer").unwrap();
             }
         }
     };
-    println!();
+    writeln!(out).unwrap();
 
     user_selection
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{env, io::Cursor, path::PathBuf};
+
+    /// Creates a corrupt player database file in a temporary directory.
+    fn corrupt_player_file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("wordle-rs-{}-{name}.txt", std::process::id()));
+        fs::write(&path, "not a player database").unwrap();
+        path
+    }
+
+    #[test]
+    fn corrupt_player_recovered() {
+        let path = corrupt_player_file("recovered");
+        let filename = path.to_str().unwrap();
+        let backup = PathBuf::from(filename.to_owned() + ".corrupt");
+        /* a backup from a failed save must not be replaced */
+        let saved_progress = PathBuf::from(filename.to_owned() + ".bak");
+        fs::write(&saved_progress, "saved progress").unwrap();
+
+        let player = recover_corrupt_player(
+            String::from("player"),
+            filename,
+            &mut Cursor::new("y\n"),
+            &mut io::sink(),
+        );
+
+        assert_eq!(player, Some(PlayerInfo::new(String::from("player"))));
+        assert!(!path.exists());
+        assert_eq!(
+            fs::read_to_string(&backup).unwrap(),
+            "not a player database"
+        );
+        assert_eq!(
+            fs::read_to_string(&saved_progress).unwrap(),
+            "saved progress"
+        );
+        fs::remove_file(backup).unwrap();
+        fs::remove_file(saved_progress).unwrap();
+    }
+
+    #[test]
+    fn corrupt_player_not_recovered() {
+        let path = corrupt_player_file("not-recovered");
+        let filename = path.to_str().unwrap();
+
+        let player = recover_corrupt_player(
+            String::from("player"),
+            filename,
+            &mut Cursor::new("n\n"),
+            &mut io::sink(),
+        );
+
+        assert_eq!(player, None);
+        assert!(path.exists());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn leaderboard_is_sorted() {
+        let mut alice = PlayerInfo::new("alice");
+        alice.add_won_word(String::from("TRACE"), 3);
+        alice.add_won_word(String::from("CRATE"), 3);
+        alice.add_lost_word(String::from("BLIND"));
+        let mut bob = PlayerInfo::new("bob");
+        bob.add_won_word(String::from("TRACE"), 3);
+        bob.add_won_word(String::from("CRATE"), 3);
+        let mut carol = PlayerInfo::new("carol");
+        carol.add_won_word(String::from("TRACE"), 3);
+        let dave = PlayerInfo::new("dave");
+        let mut erin = PlayerInfo::new("erin");
+        erin.add_won_word(String::from("TRACE"), 3);
+
+        let rows = leaderboard_rows([carol, dave, alice, erin, bob]);
+        let usernames: Vec<_> = rows.iter().map(|row| row.username.as_str()).collect();
+        assert_eq!(usernames, ["bob", "alice", "carol", "erin", "dave"]);
+        assert_eq!(
+            rows[1],
+            LeaderboardRow {
+                username: String::from("alice"),
+                max_win_streak: 2,
+                win_rate: 67,
+            }
+        );
+    }
+
+    #[test]
+    fn users_sorted_by_recency() {
+        let usernames = BTreeSet::from([
+            String::from("alice"),
+            String::from("bob"),
+            String::from("carol"),
+            String::from("dave"),
+        ]);
+        let mut recent_users = Vec::new();
+        log_recent_user(&mut recent_users, "bob");
+        log_recent_user(&mut recent_users, "dave");
+        log_recent_user(&mut recent_users, "bob");
+
+        assert_eq!(recent_users, ["bob", "dave"]);
+        assert_eq!(
+            sort_by_recency(&usernames, &recent_users),
+            ["bob", "dave", "alice", "carol"]
+        );
+    }
+
+    #[test]
+    fn deleted_recent_users_are_skipped() {
+        let usernames = BTreeSet::from([String::from("alice"), String::from("bob")]);
+        let recent_users = [String::from("carol"), String::from("bob")];
+
+        assert_eq!(sort_by_recency(&usernames, &recent_users), ["bob", "alice"]);
+    }
+
+    #[test]
+    fn users_listed_by_recency_when_requested() {
+        let usernames = BTreeSet::from([
+            String::from("alice"),
+            String::from("bob"),
+            String::from("carol"),
+        ]);
+        let recent_users = [String::from("carol")];
+
+        let options = GameOptions::default();
+        assert_eq!(
+            listed_usernames(&usernames, &recent_users, &options),
+            ["alice", "bob", "carol"]
+        );
+
+        let options = GameOptions {
+            sort_users_by_recency: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            listed_usernames(&usernames, &recent_users, &options),
+            ["carol", "alice", "bob"]
+        );
+    }
+
+    #[test]
+    fn failed_save_uses_backup() {
+        /* a directory cannot be written to as a file */
+        let path =
+            env::temp_dir().join(format!("wordle-rs-{}-save-backup.txt", std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+        let filename = path.to_str().unwrap();
+
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        let saved_filename = save_player(&player, filename).unwrap();
+
+        let backup_filename = filename.to_owned() + ".bak";
+        assert_eq!(saved_filename, backup_filename);
+        assert_eq!(
+            PlayerInfo::from_file(&backup_filename).unwrap(),
+            Some(player)
+        );
+        fs::remove_file(backup_filename).unwrap();
+        fs::remove_dir(path).unwrap();
+    }
+
+    #[test]
+    fn menu_options_keep_their_numbers() {
+        let selections = (1..=9).map(|i| UserSelection::try_from(i).ok());
+        assert!(selections.eq([
+            Some(UserSelection::PlayGame),
+            Some(UserSelection::ViewStats),
+            Some(UserSelection::LogOff),
+            Some(UserSelection::DeleteUser),
+            Some(UserSelection::PlaySharedPuzzle),
+            Some(UserSelection::PracticeWord),
+            Some(UserSelection::KeepPlaying),
+            Some(UserSelection::ViewLeaderboard),
+            None,
+        ]));
+    }
+
+    #[test]
+    fn played_shared_puzzle_is_practice() {
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+        let options = GameOptions::default();
+
+        assert!(shared_puzzle_options(&player, "TRACE", &options).practice);
+        assert!(!shared_puzzle_options(&player, "CRATE", &options).practice);
+    }
+
+    #[test]
+    fn practice_word_is_validated() {
+        let dictionary: HashSet<_> = ["CRATE", "TRACE"].into_iter().map(str::to_owned).collect();
+
+        /* too short, multibyte, not in the dictionary, then a valid word */
+        let mut input = Cursor::new("TRAC\nÉÉA\nCAFÉS\nBLIND\n t r a c e \n");
+        assert_eq!(
+            request_practice_word(&dictionary, &mut input, &mut io::sink()),
+            Some(String::from("TRACE"))
+        );
+
+        assert_eq!(
+            request_practice_word(&dictionary, &mut Cursor::new("\n"), &mut io::sink()),
+            None
+        );
+    }
+
+    #[test]
+    fn resume_is_confirmed() {
+        assert!(confirm_resume(&mut Cursor::new("y\n"), &mut io::sink()));
+        assert!(!confirm_resume(&mut Cursor::new("\n"), &mut io::sink()));
+        assert!(!confirm_resume(&mut Cursor::new(""), &mut io::sink()));
+    }
+
+    #[test]
+    fn quiet_prompts_print_nothing() {
+        let dictionary: HashSet<_> = ["TRACE"].into_iter().map(str::to_owned).collect();
+
+        for quiet in [false, true] {
+            let mut out = Vec::new();
+            let mut shown = quiet_output(&mut out, quiet);
+            assert_eq!(
+                request_puzzle_id(&mut Cursor::new("x\n#42\n"), &mut shown),
+                Some(42)
+            );
+            assert_eq!(
+                request_practice_word(&dictionary, &mut Cursor::new("BLIND\nTRACE\n"), &mut shown),
+                Some(String::from("TRACE"))
+            );
+            assert!(confirm_resume(&mut Cursor::new("y\n"), &mut shown));
+            drop(shown);
+
+            let out = String::from_utf8(out).unwrap();
+            if quiet {
+                assert_eq!(out, "");
+            } else {
+                assert!(out.contains("Error: invalid puzzle number"));
+                assert!(out.contains("Error: word must be in the dictionary"));
+                assert!(out.contains("Resume? [y/N] "));
+            }
+        }
+    }
+
+    #[test]
+    fn log_in_creates_new_player() {
+        let username = env::temp_dir()
+            .join(format!("wordle-rs-{}-new-user", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let mut usernames = BTreeSet::from([String::from("alice")]);
+        let mut recent_users = vec![String::from("alice")];
+
+        let player = log_in(username.clone(), &mut usernames, &mut recent_users);
+
+        assert_eq!(player, Some(PlayerInfo::new(username.clone())));
+        assert!(usernames.contains(&username));
+        assert_eq!(recent_users, [username, String::from("alice")]);
+    }
+
+    #[test]
+    fn log_in_loads_existing_player() {
+        let username = env::temp_dir()
+            .join(format!("wordle-rs-{}-existing-user", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let filename = username.clone() + ".txt";
+        let mut saved_player = PlayerInfo::new(username.clone());
+        saved_player.add_won_word(String::from("TRACE"), 3);
+        saved_player.write_to_file(&filename).unwrap();
+
+        let mut usernames = BTreeSet::from([username.clone()]);
+        let player = log_in(username, &mut usernames, &mut Vec::new());
+        fs::remove_file(filename).unwrap();
+
+        assert_eq!(player, Some(saved_player));
+    }
+
+    #[test]
+    fn quiet_log_in_rejects_corrupt_player() {
+        let path = corrupt_player_file("quiet-log-in");
+        let username = path.to_str().unwrap().strip_suffix(".txt").unwrap();
+        let options = GameOptions {
+            quiet: true,
+            ..GameOptions::default()
+        };
+
+        /* this would wait for an answer on stdin if the user was asked to start over */
+        let player = log_in_with(
+            username.to_owned(),
+            &mut BTreeSet::new(),
+            &mut Vec::new(),
+            &options,
+        );
+
+        assert_eq!(player, None);
+        assert!(path.exists());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn selection_carries_player() {
+        let dictionary: HashSet<String> = HashSet::new();
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+
+        let state = run_selection(
+            UserSelection::ViewStats,
+            player.clone(),
+            &dictionary,
+            &GameOptions::default(),
+        );
+        assert_eq!(state, ProgramState::MainMenu(player.clone()));
+
+        /* there are no words to play, so the player is unchanged */
+        let state = run_selection(
+            UserSelection::PlayGame,
+            player.clone(),
+            &dictionary,
+            &GameOptions::default(),
+        );
+        assert_eq!(state, ProgramState::MainMenu(player.clone()));
+
+        let state = run_selection(
+            UserSelection::LogOff,
+            player,
+            &dictionary,
+            &GameOptions::default(),
+        );
+        assert_eq!(state, ProgramState::LogIn);
+    }
+
+    #[test]
+    fn games_are_played_until_quit() {
+        let username = env::temp_dir()
+            .join(format!("wordle-rs-{}-keep-playing", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let mut player = PlayerInfo::new(username.as_str());
+        let words = ["BLIND", "CRATE", "TRACE"];
+        let dictionary: HashSet<_> = words.into_iter().map(str::to_owned).collect();
+        let options = GameOptions {
+            quiet: true,
+            ..Default::default()
+        };
+
+        /* guess the words in order until the answer is found, then quit the third game */
+        let mut games_started = 0;
+        keep_playing(&mut player, &dictionary, &options, |turn| {
+            if turn == 1 {
+                games_started += 1;
+            }
+            match games_started {
+                3 => Some(String::from(":q")),
+                _ => Some(words[turn - 1].to_owned()),
+            }
+        });
+
+        assert_eq!(games_started, 3);
+        assert_eq!(player.games_played(), 2);
+        assert_eq!(player.games_won(), 2);
+
+        let filename = username + ".txt";
+        let saved_player = PlayerInfo::from_file(&filename).unwrap().unwrap();
+        fs::remove_file(&filename).unwrap();
+        assert_eq!(saved_player.games_won(), 2);
+    }
+}
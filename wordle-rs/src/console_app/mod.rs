@@ -17,86 +17,262 @@
 //!
 //! **`console_app`**
 //!
+//! Loading a gzip-compressed dictionary also requires **`gzip`**.
+//!
 //! Author: Benjamin Hall
 
 use std::{
     collections::{BTreeSet, HashSet},
     fs::{self, File},
-    io::{self, BufWriter},
+    io::{self, BufReader, BufWriter, Read, Write},
 };
 
-use self::main_menu::ProgramState;
+use self::{game::GameOptions, main_menu::ProgramState};
+use crate::normalize::{AsciiUppercase, Normalizer};
 
 pub mod game;
 pub mod main_menu;
+pub mod saved_game;
 
 /// Name of the usernames database file.
 pub const USERNAMES_FILENAME: &str = "users.txt";
+/// Name of the recent users database file.
+pub const RECENT_USERS_FILENAME: &str = "recent_users.txt";
+
+/// Seeds the random number generator used to choose words.
+///
+/// This makes the words chosen for a whole session reproducible,
+/// such as for a bug report. The generator is global state shared
+/// by everything that runs on the current thread, which is the
+/// whole program for the console application, so this should be
+/// called once before [`run`].
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::console_app;
+/// console_app::seed_rng(42);
+/// ```
+#[inline]
+pub fn seed_rng(seed: u64) {
+    fastrand::seed(seed);
+}
+
+/// Loads a dictionary of valid words from a file.
+///
+/// Each line of the file is a word. Words that are not
+/// [`WORD_LEN`](crate::WORD_LEN) letters long are skipped, and
+/// the rest are converted to uppercase. Answers only use the
+/// letters A-Z, so words with any other letters are also skipped,
+/// with a warning that names each of them. A byte order mark at the
+/// start of the file is ignored. If the file name ends in ".gz",
+/// the file is decompressed with gzip first.
+///
+/// Since words of other lengths are skipped, the file may be
+/// a general word list with words of many lengths, and the words
+/// loaded are still all the same length. Use
+/// [`assert_uniform_length`](crate::assert_uniform_length) to
+/// check a dictionary that was not loaded from a file.
+///
+/// Warnings are printed to [`io::stdout`].
+///
+/// # Errors
+///
+/// This function will return an error if opening the file or
+/// reading from the file fails. It will also return an error if the file name ends in ".gz"
+/// and the **`gzip`** feature is not enabled.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::io;
+/// # use wordle::console_app;
+/// # fn main() -> io::Result<()> {
+/// let dictionary = console_app::load_dictionary("dictionary.txt")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn load_dictionary(filename: &str) -> io::Result<HashSet<String>> {
+    load_dictionary_with(filename, &GameOptions::default())
+}
+
+/// Loads a dictionary of valid words from a file,
+/// following the given game options.
+///
+/// This works the same as [`load_dictionary`], except that
+/// no warnings are shown for skipped words if the options
+/// are quiet.
+///
+/// # Errors
+///
+/// See [`load_dictionary`].
+///
+/// # Panics
+///
+/// Panics if writing to [`io::stdout`] fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::io;
+/// # use wordle::console_app::{self, game::GameOptions};
+/// # fn main() -> io::Result<()> {
+/// let options = GameOptions {
+///     quiet: true,
+///     ..GameOptions::default()
+/// };
+///
+/// let dictionary = console_app::load_dictionary_with("dictionary.txt", &options)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn load_dictionary_with(filename: &str, options: &GameOptions) -> io::Result<HashSet<String>> {
+    let file = File::open(filename)?;
+    let mut contents = String::new();
+
+    if filename.ends_with(".gz") {
+        #[cfg(feature = "gzip")]
+        flate2::read::GzDecoder::new(BufReader::new(file)).read_to_string(&mut contents)?;
+        #[cfg(not(feature = "gzip"))]
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Error: gzip dictionaries require the gzip feature",
+        ));
+    } else {
+        BufReader::new(file).read_to_string(&mut contents)?;
+    }
+
+    let (dictionary, skipped) = parse_dictionary(crate::players::database::strip_bom(&contents));
+    let mut stdout = io::stdout();
+    let mut out = main_menu::quiet_output(&mut stdout, options.quiet);
+    for (line_number, word) in skipped {
+        writeln!(
+            out,
+            "Warning: skipped word on line {line_number} with letters other than A-Z: {word}"
+        )
+        .unwrap();
+    }
+    Ok(dictionary)
+}
+
+/// Parses the words of a dictionary file.
+///
+/// The dictionary is returned along with the line number
+/// (starting at 1) of each word that was skipped for having
+/// letters other than A-Z. See [`load_dictionary`].
+fn parse_dictionary(contents: &str) -> (HashSet<String>, Vec<(usize, &str)>) {
+    let mut skipped = Vec::new();
+    let dictionary = contents
+        .lines()
+        .enumerate()
+        /* count letters rather than bytes, so letters outside of A-Z are kept to be reported */
+        .filter(|(_, s)| s.chars().count() == crate::WORD_LEN)
+        .filter(|&(i, s)| {
+            let is_ascii = s.chars().all(|c| c.is_ascii_alphabetic());
+            if !is_ascii {
+                skipped.push((i + 1, s));
+            }
+            is_ascii
+        })
+        .map(|(_, s)| AsciiUppercase.normalize(s))
+        .collect();
+    (dictionary, skipped)
+}
 
 /// Runs the main state machine of the Wordle console program.
 ///
 /// This function manages the various states of the program
 /// after all necessary variables have been initialized,
-/// such as the dictionary and the set of existing usernames.
+/// such as the dictionary, the set of existing usernames, and
+/// the list of recent users (most recent first).
+///
+/// If a username is given, that user is logged in without
+/// being asked for their username. After they log off, the
+/// program asks for a username as usual.
 ///
 /// # Examples
 ///
 /// Basic usage:
 /// ```no_run
 /// # use std::collections::{BTreeSet, HashSet};
-/// # use wordle::console_app;
+/// # use wordle::console_app::{self, game::GameOptions};
 /// # fn read_dictionary(filename: &str) -> HashSet<String> {
 /// # HashSet::new()
 /// # }
 /// # fn read_usernames(filename: &str) -> BTreeSet<String> {
 /// # BTreeSet::new()
 /// # }
+/// # fn read_recent_users(filename: &str) -> Vec<String> {
+/// # Vec::new()
+/// # }
 /// let dictionary: HashSet<String> =
 ///     read_dictionary("dictionary.txt");
 /// let mut usernames: BTreeSet<String> =
 ///     read_usernames("usernames.txt");
-/// console_app::run(&dictionary, &mut usernames);
+/// let mut recent_users: Vec<String> =
+///     read_recent_users("recent_users.txt");
+/// let options = GameOptions::default();
+/// console_app::run(&dictionary, &mut usernames, &mut recent_users, None, &options);
 /// ```
 pub fn run(
     dictionary: &HashSet<String, impl std::hash::BuildHasher>,
     usernames: &mut BTreeSet<String>,
+    recent_users: &mut Vec<String>,
+    username: Option<String>,
+    options: &GameOptions,
 ) {
     let mut state = ProgramState::LogIn;
-    let mut current_player = None;
+    /* the given user is only logged in automatically the first time */
+    let mut username = username;
 
     loop {
         state = match state {
             ProgramState::LogIn => {
-                current_player = main_menu::request_user_login(usernames);
+                let current_player = match username.take() {
+                    Some(username) => {
+                        main_menu::log_in_with(username, usernames, recent_users, options)
+                    }
+                    None => main_menu::request_user_login_with(usernames, recent_users, options),
+                };
                 match current_player {
-                    Some(_) => {
-                        if save_usernames(usernames, USERNAMES_FILENAME).is_err() {
+                    Some(mut current_player) => {
+                        main_menu::offer_resume(&mut current_player, dictionary, options);
+                        if save_usernames(usernames.iter(), USERNAMES_FILENAME).is_err()
+                            || save_usernames(recent_users.iter(), RECENT_USERS_FILENAME).is_err()
+                        {
                             println!("Error: could not write to the user database");
                             ProgramState::Exit
                         } else {
                             /* user has logged in, continue to the main menu */
-                            ProgramState::MainMenu
+                            ProgramState::MainMenu(current_player)
                         }
                     }
                     /* user requested to exit, or there was an error */
                     None => ProgramState::Exit,
                 }
             }
-            ProgramState::MainMenu => {
-                /* cannot enter this state unless current_player is Some */
-                let current_player = unsafe { current_player.as_mut().unwrap_unchecked() };
-                main_menu::run(current_player, dictionary)
+            ProgramState::MainMenu(current_player) => {
+                main_menu::run(current_player, dictionary, options)
+            }
+            ProgramState::ViewLeaderboard(current_player) => {
+                main_menu::show_leaderboard(usernames);
+                ProgramState::MainMenu(current_player)
             }
-            ProgramState::DeleteUser => {
-                /* cannot enter this state unless current_player is Some */
-                let current_player = unsafe { current_player.as_ref().unwrap_unchecked() };
+            ProgramState::DeleteUser(current_player) => {
                 /* remove the current player from the databse */
                 let username = current_player.get_username();
                 usernames.remove(username);
+                recent_users.retain(|name| name != username);
                 _ = fs::remove_file(username.to_owned() + ".txt");
+                _ = fs::remove_file(saved_game::saved_game_filename(username));
 
-                /* save the username database */
-                if save_usernames(usernames, USERNAMES_FILENAME).is_err() {
+                /* save the username databases */
+                if save_usernames(usernames.iter(), USERNAMES_FILENAME).is_err()
+                    || save_usernames(recent_users.iter(), RECENT_USERS_FILENAME).is_err()
+                {
                     println!("Error: could not write to the user database");
                     ProgramState::Exit
                 } else {
@@ -110,7 +286,7 @@ pub fn run(
     }
 }
 
-/// Saves a set of usernames to the usernames database file.
+/// Saves a list of usernames to a usernames database file.
 ///
 /// Any errors, such as failing to open the file or not having
 /// read access, will be propagated up to the caller.
@@ -127,12 +303,14 @@ pub fn run(
 /// # fn main() -> io::Result<()> {
 /// let mut usernames = BTreeSet::new();
 /// usernames.insert(String::from("user"));
-/// console_app::save_usernames(&usernames, "users.txt")?;
+/// console_app::save_usernames(usernames.iter(), "users.txt")?;
 /// # Ok(())
 /// # }
 /// ```
-fn save_usernames(usernames: &BTreeSet<String>, filename: &str) -> io::Result<()> {
-    use std::io::Write;
+fn save_usernames<'a>(
+    usernames: impl Iterator<Item = &'a String>,
+    filename: &str,
+) -> io::Result<()> {
     let file = File::create(filename)?;
     let mut writer = BufWriter::new(file);
     for username in usernames {
@@ -141,3 +319,92 @@ fn save_usernames(usernames: &BTreeSet<String>, filename: &str) -> io::Result<()
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::players::PlayerInfo;
+
+    #[test]
+    fn same_seed_same_first_word() {
+        let dictionary: HashSet<_> = ["BLIND", "CRANE", "CRATE", "SPEED", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let player = PlayerInfo::new("player");
+
+        let first_words: Vec<_> = (0..2)
+            .map(|_| {
+                seed_rng(42);
+                player.get_random_word(&dictionary).unwrap().to_owned()
+            })
+            .collect();
+
+        assert_eq!(first_words[0], first_words[1]);
+    }
+
+    #[test]
+    fn dictionary_with_bom_is_loaded() {
+        let path = std::env::temp_dir().join(format!("wordle-rs-{}-bom.txt", std::process::id()));
+        fs::write(&path, "\u{feff}trace\r\ncrate\r\n").unwrap();
+
+        let dictionary = load_dictionary(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let expected: HashSet<_> = ["TRACE", "CRATE"].into_iter().map(str::to_owned).collect();
+        assert_eq!(dictionary.unwrap(), expected);
+    }
+
+    #[test]
+    fn mixed_length_dictionary_is_loaded() {
+        let path = std::env::temp_dir().join(format!("wordle-rs-{}-mixed.txt", std::process::id()));
+        fs::write(&path, "trace\ntraces\ncrate\ncat\n").unwrap();
+
+        let dictionary = load_dictionary(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let dictionary = dictionary.unwrap();
+        assert_eq!(crate::assert_uniform_length(&dictionary), Ok(5));
+        assert_eq!(dictionary.len(), 2);
+    }
+
+    #[test]
+    fn accented_words_are_skipped() {
+        let path =
+            std::env::temp_dir().join(format!("wordle-rs-{}-accented.txt", std::process::id()));
+        fs::write(&path, "trace\nCAFÉS\ncafé\nÉÉA\ncrate\n").unwrap();
+
+        let dictionary = load_dictionary(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let expected: HashSet<_> = ["TRACE", "CRATE"].into_iter().map(str::to_owned).collect();
+        assert_eq!(dictionary.unwrap(), expected);
+
+        let (_, skipped) = parse_dictionary("trace\nCAFÉS\ncafé\nÉÉA\ncrate\n");
+        assert_eq!(skipped, [(2, "CAFÉS")]);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_dictionary_is_loaded() {
+        use std::{env, io::Write};
+
+        use flate2::{write::GzEncoder, Compression};
+
+        let path = env::temp_dir().join(format!("wordle-rs-{}-dict.txt.gz", std::process::id()));
+        let mut encoder = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+        encoder
+            .write_all(b"trace\nCRATE\ntoolong\nblind\n")
+            .unwrap();
+        encoder.finish().unwrap();
+
+        let dictionary = load_dictionary(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let expected: HashSet<_> = ["TRACE", "CRATE", "BLIND"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        assert_eq!(dictionary.unwrap(), expected);
+    }
+}
@@ -0,0 +1,287 @@
+//! This module provides methods to save a game of Wordle
+//! that is still in progress, so it can be resumed later.
+//!
+//! ## Required features
+//!
+//! **`console_app`**
+//!
+//! Author: Benjamin Hall
+
+use std::{
+    convert::identity,
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+};
+
+use crate::players::database;
+
+/// Stores the state of a game of Wordle that is still in progress.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SavedGame {
+    /// The answer to the game
+    pub answer: String,
+    /// The puzzle number, if the game is a shared puzzle
+    pub puzzle_id: Option<u32>,
+    /// All guesses made so far, in order
+    pub guesses: Vec<String>,
+    /// The number of hints used so far
+    pub num_hints: usize,
+}
+
+impl fmt::Display for SavedGame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Answer: {}", self.answer)?;
+        writeln!(
+            f,
+            "Puzzle Number: {}",
+            self.puzzle_id.map(|id| id.to_string()).unwrap_or_default()
+        )?;
+        writeln!(f, "Guesses: {}", self.guesses.join(","))?;
+        writeln!(f, "Hints: {}", self.num_hints)
+    }
+}
+
+/// Gets the name of the file a player's game in progress is saved to.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::console_app::saved_game;
+/// assert_eq!(saved_game::saved_game_filename("user"), "user.game");
+/// ```
+#[inline]
+#[must_use]
+pub fn saved_game_filename(username: &str) -> String {
+    username.to_owned() + ".game"
+}
+
+impl SavedGame {
+    /// Reads a saved game from a string.
+    ///
+    /// If the data is not a valid saved game, this
+    /// function returns None. This includes an answer or
+    /// guess that is not [`WORD_LEN`](crate::WORD_LEN)
+    /// uppercase letters from A to Z, a guess of the answer,
+    /// and guesses and hints that use up every turn.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::console_app::saved_game::SavedGame;
+    /// let saved = SavedGame {
+    ///     answer: String::from("TRACE"),
+    ///     puzzle_id: Some(42),
+    ///     guesses: vec![String::from("CRATE")],
+    ///     num_hints: 0,
+    /// };
+    /// assert_eq!(SavedGame::parse(&saved.to_string()), Some(saved));
+    /// ```
+    #[must_use]
+    pub fn parse(game_data: &str) -> Option<Self> {
+        /* use a borrowed string as the key for all entries */
+        type DatabaseEntry<'a, T, V> = database::Entry<&'a str, T, V>;
+
+        let lines: Vec<&str> = database::strip_bom(game_data).lines().collect();
+        if lines.len() != 4 {
+            return None;
+        }
+
+        let answer = DatabaseEntry::from_line(lines[0], str::to_owned)?;
+        let puzzle_id = DatabaseEntry::<_, ()>::from_line(lines[1], identity)?;
+        let guesses = DatabaseEntry::<Vec<_>, _>::from_collection(lines[2], str::to_owned)?;
+        let num_hints = DatabaseEntry::try_from_line(lines[3], str::parse::<usize>).ok()??;
+
+        let puzzle_id = match puzzle_id.value {
+            "" => None,
+            id => Some(id.parse().ok()?),
+        };
+
+        /* a corrupt word would not be safe to check guesses against */
+        if !is_saved_word(&answer.value) || !guesses.value.iter().all(|g| is_saved_word(g)) {
+            return None;
+        }
+        /* a game that was won or used every turn would not have been saved */
+        if guesses.value.len() + num_hints.value >= crate::MAX_GUESSES
+            || guesses.value.contains(&answer.value)
+        {
+            return None;
+        }
+
+        Some(Self {
+            answer: answer.value,
+            puzzle_id,
+            guesses: guesses.value,
+            num_hints: num_hints.value,
+        })
+    }
+
+    /// Reads a saved game from a file.
+    ///
+    /// If the file does not exist, this function returns None.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if reading from
+    /// the file fails, or if the file is not a valid saved game.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// # use std::io;
+    /// # use wordle::console_app::saved_game::SavedGame;
+    /// # fn main() -> io::Result<()> {
+    /// let saved = SavedGame::from_file("user.game")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_file(filename: &str) -> io::Result<Option<Self>> {
+        let Ok(file) = File::open(filename) else {
+            return Ok(None);
+        };
+
+        let mut game_data = String::new();
+        BufReader::new(file).read_to_string(&mut game_data)?;
+
+        Self::parse(&game_data).map(Some).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Error: corrupt saved game file: {filename}"),
+            )
+        })
+    }
+
+    /// Writes this saved game to a file.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if creating the
+    /// file or writing to the file fails.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// # use std::io;
+    /// # use wordle::console_app::saved_game::SavedGame;
+    /// # fn main() -> io::Result<()> {
+    /// let saved = SavedGame {
+    ///     answer: String::from("TRACE"),
+    ///     ..Default::default()
+    /// };
+    /// saved.write_to_file("user.game")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to_file(&self, filename: &str) -> io::Result<()> {
+        let file = File::create(filename)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(self.to_string().as_bytes())
+    }
+}
+
+/// Checks whether a word could have been saved by a game,
+/// which only uses uppercase letters from A to Z.
+fn is_saved_word(word: &str) -> bool {
+    word.len() == crate::WORD_LEN && word.bytes().all(|c| c.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs};
+
+    use super::*;
+
+    #[test]
+    fn saved_game_round_trip() {
+        let saved = SavedGame {
+            answer: String::from("TRACE"),
+            puzzle_id: Some(7),
+            guesses: vec![String::from("BLIND"), String::from("CRATE")],
+            num_hints: 1,
+        };
+        assert_eq!(SavedGame::parse(&saved.to_string()), Some(saved));
+
+        let fresh = SavedGame {
+            answer: String::from("TRACE"),
+            ..Default::default()
+        };
+        assert_eq!(SavedGame::parse(&fresh.to_string()), Some(fresh));
+
+        assert_eq!(SavedGame::parse("Answer: TRACE\n"), None);
+        assert_eq!(
+            SavedGame::parse("Answer: TRACE\nPuzzle Number: x\nGuesses: \nHints: 0\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn finished_games_are_rejected() {
+        for (guesses, hints) in [
+            ("BLIND,CRATE,TRACE", 0),
+            ("BLIND,CRATE,CRANE,SPEED,ABBEY,BEBOP", 0),
+            ("BLIND", 5),
+            ("", 9),
+        ] {
+            let game_data =
+                format!("Answer: TRACE\nPuzzle Number: \nGuesses: {guesses}\nHints: {hints}\n");
+            assert_eq!(SavedGame::parse(&game_data), None);
+        }
+
+        let game_data = "Answer: TRACE\nPuzzle Number: \nGuesses: BLIND,CRATE\nHints: 3\n";
+        assert!(SavedGame::parse(game_data).is_some());
+    }
+
+    #[test]
+    fn corrupt_words_are_rejected() {
+        for (answer, guesses) in [
+            ("TRACES", "CRATE"),
+            ("trace", "CRATE"),
+            ("CAFÉS", "CRATE"),
+            ("TRACE", "CRATE,BLI"),
+            ("TRACE", "CRATE,BL1ND"),
+        ] {
+            let game_data =
+                format!("Answer: {answer}\nPuzzle Number: \nGuesses: {guesses}\nHints: 0\n");
+            assert_eq!(SavedGame::parse(&game_data), None);
+        }
+
+        let filename = env::temp_dir()
+            .join(format!("wordle-rs-{}-corrupt.game", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        fs::write(
+            &filename,
+            "Answer: TRACE\nPuzzle Number: \nGuesses: CRATE,XY\nHints: 0\n",
+        )
+        .unwrap();
+        let restored = SavedGame::from_file(&filename);
+        fs::remove_file(&filename).unwrap();
+
+        assert_eq!(restored.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn saved_game_file_round_trip() {
+        let filename = env::temp_dir()
+            .join(format!("wordle-rs-{}-round-trip.game", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let saved = SavedGame {
+            answer: String::from("TRACE"),
+            puzzle_id: None,
+            guesses: vec![String::from("CRATE")],
+            num_hints: 0,
+        };
+
+        saved.write_to_file(&filename).unwrap();
+        let restored = SavedGame::from_file(&filename).unwrap();
+        fs::remove_file(&filename).unwrap();
+
+        assert_eq!(restored, Some(saved));
+        assert_eq!(SavedGame::from_file(&filename).unwrap(), None);
+    }
+}
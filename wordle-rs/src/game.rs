@@ -0,0 +1,577 @@
+//! This module provides a struct to manage the state of a
+//! game of Wordle, so a frontend can drive the game one
+//! guess at a time.
+//!
+//! Author: Benjamin Hall
+
+use std::{borrow::Borrow, collections::HashSet};
+
+use crate::{
+    validate_guess, word_list::WordList, GameResult, GuessError, WordleAnswer, WordleGuess,
+    MAX_GUESSES, WORD_LEN,
+};
+
+/// Colors of each letter of one guess.
+pub type GuessRow = [WordleGuess; WORD_LEN];
+
+/// Possible outcomes of a finished game of Wordle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GameOutcome {
+    /// The player found the answer, using the given number of
+    /// guesses, including any used up by hints
+    Won(usize),
+    /// The player used every guess without finding the answer
+    Lost,
+}
+
+/// Stores the state of a game of Wordle.
+///
+/// Guesses are made one at a time with [`Game::submit_guess`],
+/// and are checked against a [`WordList`] before they are played.
+/// A hint can be revealed with [`Game::reveal_hint`] in place of
+/// a guess, which uses up that guess.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::{game::{Game, GameOutcome}, WordleAnswer};
+/// let dictionary = HashSet::from([String::from("CRATE"), String::from("TRACE")]);
+/// let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+///
+/// game.submit_guess("CRATE").unwrap();
+/// assert_eq!(game.remaining(), 5);
+/// assert_eq!(game.is_over(), None);
+///
+/// game.submit_guess("TRACE").unwrap();
+/// assert_eq!(game.is_over(), Some(GameOutcome::Won(2)));
+/// ```
+#[derive(Debug)]
+pub struct Game<'a, S, W>
+where
+    S: Borrow<str>,
+{
+    answer: WordleAnswer<S>,
+    dictionary: &'a W,
+    /* words accepted in place of the answer */
+    aliases: HashSet<String>,
+    history: Vec<(String, GuessRow)>,
+    /* positions of the answer the player has already found */
+    known: [bool; WORD_LEN],
+    num_hints: usize,
+    max_guesses: usize,
+}
+
+/* the dictionary is only borrowed, so it does not need to be Clone */
+impl<S, W> Clone for Game<'_, S, W>
+where
+    S: Borrow<str> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            answer: self.answer.clone(),
+            dictionary: self.dictionary,
+            aliases: self.aliases.clone(),
+            history: self.history.clone(),
+            known: self.known,
+            num_hints: self.num_hints,
+            max_guesses: self.max_guesses,
+        }
+    }
+}
+
+impl<'a, S, W> Game<'a, S, W>
+where
+    S: Borrow<str>,
+    W: WordList,
+{
+    /// Starts a new game with [`MAX_GUESSES`] guesses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the answer is not [`WORD_LEN`] letters long.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, WordleAnswer, MAX_GUESSES};
+    /// let dictionary = HashSet::from([String::from("TRACE")]);
+    /// let game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+    /// assert_eq!(game.remaining(), MAX_GUESSES);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(answer: WordleAnswer<S>, dictionary: &'a W) -> Self {
+        Self::with_max_guesses(answer, dictionary, MAX_GUESSES)
+    }
+
+    /// Starts a new game with the given number of guesses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the answer is not [`WORD_LEN`] letters long,
+    /// since every guess must be that long as well.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("TRACE")]);
+    /// let game = Game::with_max_guesses(WordleAnswer::new("TRACE"), &dictionary, 3);
+    /// assert_eq!(game.remaining(), 3);
+    /// ```
+    #[must_use]
+    pub fn with_max_guesses(
+        answer: WordleAnswer<S>,
+        dictionary: &'a W,
+        max_guesses: usize,
+    ) -> Self {
+        assert_eq!(
+            answer.get_word().chars().count(),
+            WORD_LEN,
+            "answer must be {WORD_LEN} letters long"
+        );
+        Self {
+            answer,
+            dictionary,
+            aliases: HashSet::new(),
+            history: Vec::new(),
+            known: [false; WORD_LEN],
+            num_hints: 0,
+            max_guesses,
+        }
+    }
+
+    /// Accepts other spellings in place of the answer, such as
+    /// "GRAYS" for "GREYS".
+    ///
+    /// An alias is accepted as a guess even if it is not in the
+    /// word list, and guessing it counts as finding the answer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::{Game, GameOutcome}, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("GREYS")]);
+    /// let mut game = Game::new(WordleAnswer::new("GREYS"), &dictionary)
+    ///     .with_aliases(HashSet::from([String::from("GRAYS")]));
+    ///
+    /// game.submit_guess("GRAYS").unwrap();
+    /// assert_eq!(game.is_over(), Some(GameOutcome::Won(1)));
+    /// ```
+    #[must_use]
+    pub fn with_aliases(self, aliases: HashSet<String>) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Checks whether a guess is accepted in place of the answer.
+    ///
+    /// See [`Game::with_aliases`].
+    #[inline]
+    #[must_use]
+    pub fn is_alias(&self, guess: &str) -> bool {
+        self.aliases.contains(guess)
+    }
+
+    /// Checks whether a guess can be played, without playing it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`GuessError::GameOver`] if the
+    /// game is already over, or an error from [`validate_guess`]
+    /// if the guess is not valid. An alias is always valid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, GuessError, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("TRACE")]);
+    /// let game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+    ///
+    /// assert_eq!(game.validate_guess("TRACE"), Ok(()));
+    /// assert_eq!(game.validate_guess("CRATE"), Err(GuessError::NotInDictionary));
+    /// assert_eq!(game.remaining(), 6);
+    /// ```
+    pub fn validate_guess(&self, guess: &str) -> Result<(), GuessError> {
+        if self.is_over().is_some() {
+            Err(GuessError::GameOver)
+        } else if self.is_alias(guess) {
+            Ok(())
+        } else {
+            validate_guess(guess, self.dictionary, WORD_LEN)
+        }
+    }
+
+    /// Plays a guess, returning the color of each letter.
+    ///
+    /// The guess should already be in the same case as the
+    /// dictionary. A guess that is not valid does not use up
+    /// a guess.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error from [`Game::validate_guess`]
+    /// if the guess cannot be played.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, guess_result, GuessError, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("CRATE"), String::from("TRACE")]);
+    /// let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+    ///
+    /// assert_eq!(game.submit_guess("CRATE"), Ok(guess_result![Y G G Y G]));
+    /// assert_eq!(game.submit_guess("BLIND"), Err(GuessError::NotInDictionary));
+    /// ```
+    pub fn submit_guess(&mut self, guess: &str) -> Result<GuessRow, GuessError> {
+        self.validate_guess(guess)?;
+        Ok(self.play_guess(guess))
+    }
+
+    /// Plays a guess from an earlier game, such as a game that
+    /// was saved, returning the color of each letter.
+    ///
+    /// The guess is not checked against the word list, since it
+    /// was already accepted when it was first played, but it
+    /// must still be [`WORD_LEN`] letters long.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`GuessError::GameOver`] if the
+    /// game is already over, or [`GuessError::WrongLength`] if the
+    /// guess is not [`WORD_LEN`] letters long.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("TRACE")]);
+    /// let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+    ///
+    /// assert!(game.restore_guess("CRATE").is_ok());
+    /// assert_eq!(game.remaining(), 5);
+    /// ```
+    pub fn restore_guess(&mut self, guess: &str) -> Result<GuessRow, GuessError> {
+        if self.is_over().is_some() {
+            return Err(GuessError::GameOver);
+        }
+        let found = guess.chars().count();
+        if found != WORD_LEN {
+            return Err(GuessError::WrongLength {
+                expected: WORD_LEN,
+                found,
+            });
+        }
+        Ok(self.play_guess(guess))
+    }
+
+    /// Adds a guess that can be played to the game, where
+    /// an alias counts as guessing the answer.
+    fn play_guess(&mut self, guess: &str) -> GuessRow {
+        let colors = if self.is_alias(guess) {
+            [WordleGuess::Correct; WORD_LEN]
+        } else {
+            self.answer.check_guess(guess)
+        };
+        for (known, color) in self.known.iter_mut().zip(colors) {
+            *known |= color == WordleGuess::Correct;
+        }
+        self.history.push((guess.to_owned(), colors));
+        colors
+    }
+
+    /// Reveals a letter of the answer the player has not yet
+    /// found, using up a guess.
+    ///
+    /// The leftmost position that is not yet known is revealed
+    /// (see [`WordleAnswer::reveal_hint`]). If the game is over,
+    /// or every letter is already known, no guess is used up
+    /// and this function returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("CRATE")]);
+    /// let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+    /// game.submit_guess("CRATE").unwrap();
+    ///
+    /// assert_eq!(game.reveal_hint(), Some((0, 'T')));
+    /// assert_eq!(game.remaining(), 4);
+    /// ```
+    pub fn reveal_hint(&mut self) -> Option<(usize, char)> {
+        if self.is_over().is_some() {
+            return None;
+        }
+        let known = self.known.map(|known| {
+            if known {
+                WordleGuess::Correct
+            } else {
+                WordleGuess::Incorrect
+            }
+        });
+        let (pos, letter) = self.answer.reveal_hint(&known)?;
+        self.known[pos] = true;
+        self.num_hints += 1;
+        Some((pos, letter))
+    }
+
+    /// Gets the number of guesses the player has left.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("CRATE")]);
+    /// let mut game = Game::with_max_guesses(WordleAnswer::new("TRACE"), &dictionary, 2);
+    /// game.submit_guess("CRATE").unwrap();
+    /// assert_eq!(game.remaining(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.max_guesses
+            .saturating_sub(self.history.len() + self.num_hints)
+    }
+
+    /// Gets the number of hints the player has revealed.
+    #[inline]
+    #[must_use]
+    pub const fn num_hints(&self) -> usize {
+        self.num_hints
+    }
+
+    /// Checks whether the game is over, and how it ended.
+    ///
+    /// The game is won once a guess finds the answer, and is
+    /// lost once every guess is used without finding it. If the
+    /// game is not over, this function returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::{Game, GameOutcome}, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("CRATE")]);
+    /// let mut game = Game::with_max_guesses(WordleAnswer::new("TRACE"), &dictionary, 1);
+    /// assert_eq!(game.is_over(), None);
+    ///
+    /// game.submit_guess("CRATE").unwrap();
+    /// assert_eq!(game.is_over(), Some(GameOutcome::Lost));
+    /// ```
+    #[must_use]
+    pub fn is_over(&self) -> Option<GameOutcome> {
+        let won = self
+            .history
+            .last()
+            .is_some_and(|(_, colors)| colors.iter().all(|&c| c == WordleGuess::Correct));
+        if won {
+            Some(GameOutcome::Won(self.history.len() + self.num_hints))
+        } else if self.remaining() == 0 {
+            Some(GameOutcome::Lost)
+        } else {
+            None
+        }
+    }
+
+    /// Gets every guess made so far, along with its colors.
+    #[inline]
+    #[must_use]
+    pub fn history(&self) -> &[(String, GuessRow)] {
+        &self.history
+    }
+
+    /// Gets the answer to the game.
+    #[inline]
+    #[must_use]
+    pub const fn answer(&self) -> &WordleAnswer<S> {
+        &self.answer
+    }
+
+    /// Ends the game, returning its result.
+    ///
+    /// The game does not have to be over. If the answer has not
+    /// been found, the result has no number of guesses. Guesses
+    /// used up by hints are counted in the number of guesses.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{game::Game, WordleAnswer};
+    /// let dictionary = HashSet::from([String::from("TRACE")]);
+    /// let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+    /// game.submit_guess("TRACE").unwrap();
+    ///
+    /// let result = game.into_result();
+    /// assert_eq!(result.num_guesses, Some(1));
+    /// ```
+    #[must_use]
+    pub fn into_result(self) -> GameResult {
+        let num_guesses = match self.is_over() {
+            Some(GameOutcome::Won(num_guesses)) => Some(num_guesses),
+            _ => None,
+        };
+        GameResult {
+            answer: self.answer.get_word().to_owned(),
+            history: self.history,
+            num_guesses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::guess_result;
+    use std::collections::HashSet;
+
+    fn dictionary() -> HashSet<String> {
+        ["BLIND", "CRATE", "SPEED", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect()
+    }
+
+    #[test]
+    fn won_game() {
+        let dictionary = dictionary();
+        let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+
+        assert_eq!(game.submit_guess("BLIND"), Ok(guess_result![X X X X X]));
+        assert_eq!(
+            game.submit_guess("TRAC"),
+            Err(GuessError::WrongLength {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(game.submit_guess("ZZZZZ"), Err(GuessError::NotInDictionary));
+        assert_eq!(game.remaining(), MAX_GUESSES - 1);
+        assert_eq!(game.is_over(), None);
+
+        assert_eq!(game.submit_guess("TRACE"), Ok(guess_result![G G G G G]));
+        assert_eq!(game.is_over(), Some(GameOutcome::Won(2)));
+        assert_eq!(game.submit_guess("CRATE"), Err(GuessError::GameOver));
+        assert_eq!(game.history().len(), 2);
+        assert_eq!(game.into_result().num_guesses, Some(2));
+    }
+
+    #[test]
+    fn lost_game() {
+        let dictionary = dictionary();
+        let mut game = Game::with_max_guesses(WordleAnswer::new("TRACE"), &dictionary, 2);
+
+        game.submit_guess("CRATE").unwrap();
+        assert_eq!(game.remaining(), 1);
+        game.submit_guess("SPEED").unwrap();
+        assert_eq!(game.remaining(), 0);
+        assert_eq!(game.is_over(), Some(GameOutcome::Lost));
+        assert_eq!(game.submit_guess("TRACE"), Err(GuessError::GameOver));
+
+        let result = game.into_result();
+        assert_eq!(result.num_guesses, None);
+        assert_eq!(result.answer, "TRACE");
+    }
+
+    #[test]
+    fn hints_use_up_guesses() {
+        let dictionary = dictionary();
+        let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary);
+
+        game.submit_guess("CRATE").unwrap();
+        /* the positions found by the guess are skipped */
+        assert_eq!(game.reveal_hint(), Some((0, 'T')));
+        assert_eq!(game.reveal_hint(), Some((3, 'C')));
+        assert_eq!(game.num_hints(), 2);
+        assert_eq!(game.remaining(), MAX_GUESSES - 3);
+
+        game.submit_guess("TRACE").unwrap();
+        assert_eq!(game.reveal_hint(), None);
+        assert_eq!(game.is_over(), Some(GameOutcome::Won(4)));
+        assert_eq!(game.into_result().num_guesses, Some(4));
+    }
+
+    #[test]
+    fn hint_on_last_guess_loses() {
+        let dictionary = dictionary();
+        let mut game = Game::with_max_guesses(WordleAnswer::new("TRACE"), &dictionary, 2);
+
+        game.submit_guess("BLIND").unwrap();
+        assert!(game.reveal_hint().is_some());
+        assert_eq!(game.is_over(), Some(GameOutcome::Lost));
+    }
+
+    #[test]
+    fn alias_and_restored_guesses() {
+        let dictionary = dictionary();
+        let mut game = Game::new(WordleAnswer::new("TRACE"), &dictionary)
+            .with_aliases(HashSet::from([String::from("TRAYS")]));
+
+        /* restored guesses do not need to be in the dictionary */
+        assert_eq!(game.restore_guess("ZZZZZ"), Ok(guess_result![X X X X X]));
+        assert_eq!(
+            game.restore_guess("ZZZZ"),
+            Err(GuessError::WrongLength {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(
+            game.validate_guess("ZZZZZ"),
+            Err(GuessError::NotInDictionary)
+        );
+
+        assert!(game.is_alias("TRAYS"));
+        assert_eq!(game.validate_guess("TRAYS"), Ok(()));
+        assert_eq!(game.submit_guess("TRAYS"), Ok(guess_result![G G G G G]));
+        assert_eq!(game.is_over(), Some(GameOutcome::Won(2)));
+        assert_eq!(game.restore_guess("TRACE"), Err(GuessError::GameOver));
+    }
+
+    #[test]
+    fn clone_does_not_need_clone_word_list() {
+        /// Accepts any word, and cannot be cloned.
+        struct AnyWord;
+
+        impl WordList for AnyWord {
+            fn is_valid(&self, _: &str) -> bool {
+                true
+            }
+        }
+
+        let mut game = Game::new(WordleAnswer::new("TRACE"), &AnyWord);
+        game.submit_guess("CRATE").unwrap();
+
+        let mut copy = game.clone();
+        copy.submit_guess("TRACE").unwrap();
+        assert_eq!(game.remaining(), MAX_GUESSES - 1);
+        assert_eq!(copy.is_over(), Some(GameOutcome::Won(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "answer must be 5 letters long")]
+    fn wrong_length_answer_panics() {
+        let dictionary = dictionary();
+        _ = Game::new(WordleAnswer::new("TRACES"), &dictionary);
+    }
+}
@@ -0,0 +1,234 @@
+//! This module provides methods to track the color of each
+//! letter on the keyboard during a game of Wordle.
+//!
+//! Author: Benjamin Hall
+
+use std::collections::BTreeMap;
+
+use crate::{WordleGuess, WORD_LEN};
+
+/// Possible ways to choose a letter's color on the keyboard
+/// when it has been given different colors by different guesses.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum KeyboardPolicy {
+    /// Keep the best color the letter has ever been given,
+    /// where Incorrect < Present < Correct
+    #[default]
+    Strongest,
+    /// Use the color the letter was given by the most recent
+    /// guess that used it
+    Latest,
+}
+
+/// Finds the color of each guessed letter on the keyboard.
+///
+/// Letters that have not been guessed are not included. If a
+/// guess uses a letter more than once, the letter is given the
+/// best color it has in that guess, so a gray duplicate does not
+/// hide a green or yellow one. Across guesses, `policy` decides
+/// which color to use.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{
+/// #     guess_result,
+/// #     keyboard::{self, KeyboardPolicy},
+/// #     WordleGuess,
+/// # };
+/// let history = [
+///     (String::from("CRANE"), guess_result![G X X X X]),
+///     (String::from("SCOFF"), guess_result![X X X X X]),
+/// ];
+///
+/// let strongest = keyboard::keyboard_state(&history, KeyboardPolicy::Strongest);
+/// assert_eq!(strongest[&'C'], WordleGuess::Correct);
+///
+/// let latest = keyboard::keyboard_state(&history, KeyboardPolicy::Latest);
+/// assert_eq!(latest[&'C'], WordleGuess::Incorrect);
+/// ```
+#[must_use]
+pub fn keyboard_state(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    policy: KeyboardPolicy,
+) -> BTreeMap<char, WordleGuess> {
+    match policy {
+        KeyboardPolicy::Strongest => {
+            let mut keyboard = Keyboard::new();
+            for (guess, colors) in history {
+                keyboard.apply(guess, colors);
+            }
+            keyboard.colors
+        }
+        KeyboardPolicy::Latest => {
+            let mut keyboard = BTreeMap::new();
+            for (guess, colors) in history {
+                keyboard.extend(best_colors(guess, colors));
+            }
+            keyboard
+        }
+    }
+}
+
+/// Finds the best color of each letter in a single guess.
+fn best_colors(guess: &str, colors: &[WordleGuess; WORD_LEN]) -> BTreeMap<char, WordleGuess> {
+    let mut guess_colors = BTreeMap::new();
+    for (c, &color) in guess.chars().zip(colors) {
+        let guess_color = guess_colors.entry(c).or_insert(color);
+        *guess_color = color.max(*guess_color);
+    }
+    guess_colors
+}
+
+/// Tracks the best known color of each letter on the keyboard
+/// as guesses are made.
+///
+/// A letter's color never gets worse, where Incorrect < Present
+/// < Correct, and letters that have not been guessed are unknown.
+/// This is the same as [`keyboard_state`] with
+/// [`KeyboardPolicy::Strongest`], updated one guess at a time.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, keyboard::Keyboard, WordleGuess};
+/// let mut keyboard = Keyboard::new();
+/// keyboard.apply("CRATE", &guess_result![Y G G Y G]);
+/// assert_eq!(keyboard.get('C'), Some(WordleGuess::Present));
+/// assert_eq!(keyboard.get('Z'), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Keyboard {
+    colors: BTreeMap<char, WordleGuess>,
+}
+
+impl Keyboard {
+    /// Creates a keyboard where every letter is unknown.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            colors: BTreeMap::new(),
+        }
+    }
+
+    /// Updates the keyboard with the colors of a guess.
+    ///
+    /// If the guess uses a letter more than once, the letter is
+    /// given the best color it has in the guess. A letter keeps
+    /// its color if it already has a better one.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{guess_result, keyboard::Keyboard, WordleGuess};
+    /// let mut keyboard = Keyboard::new();
+    /// keyboard.apply("CRATE", &guess_result![Y G G Y G]);
+    /// keyboard.apply("TRACE", &guess_result![G G G G G]);
+    /// assert_eq!(keyboard.get('C'), Some(WordleGuess::Correct));
+    /// ```
+    pub fn apply(&mut self, guess: &str, result: &[WordleGuess; WORD_LEN]) {
+        for (c, color) in best_colors(guess, result) {
+            let key_color = self.colors.entry(c).or_insert(color);
+            *key_color = color.max(*key_color);
+        }
+    }
+
+    /// Gets the best known color of a letter, or None if the
+    /// letter has not been guessed.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, letter: char) -> Option<WordleGuess> {
+        self.colors.get(&letter).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::guess_result;
+
+    #[test]
+    fn strongest_keeps_best_color() {
+        let history = [
+            (String::from("CRANE"), guess_result![G X X X X]),
+            (String::from("SCOFF"), guess_result![X X X X X]),
+        ];
+
+        let keyboard = keyboard_state(&history, KeyboardPolicy::Strongest);
+        assert_eq!(keyboard[&'C'], WordleGuess::Correct);
+        assert_eq!(keyboard[&'S'], WordleGuess::Incorrect);
+        assert!(!keyboard.contains_key(&'Z'));
+    }
+
+    #[test]
+    fn latest_uses_last_color() {
+        let history = [
+            (String::from("CRANE"), guess_result![G X X X X]),
+            (String::from("SCOFF"), guess_result![X X X X X]),
+        ];
+
+        let keyboard = keyboard_state(&history, KeyboardPolicy::Latest);
+        assert_eq!(keyboard[&'C'], WordleGuess::Incorrect);
+        assert_eq!(keyboard[&'R'], WordleGuess::Incorrect);
+    }
+
+    #[test]
+    fn keyboard_never_downgrades() {
+        let mut keyboard = Keyboard::new();
+        assert_eq!(keyboard.get('A'), None);
+
+        keyboard.apply("CRANE", &guess_result![X X Y X X]);
+        assert_eq!(keyboard.get('A'), Some(WordleGuess::Present));
+        assert_eq!(keyboard.get('C'), Some(WordleGuess::Incorrect));
+
+        keyboard.apply("PLAID", &guess_result![X X G X X]);
+        assert_eq!(keyboard.get('A'), Some(WordleGuess::Correct));
+
+        /* a later worse color does not replace a better one */
+        keyboard.apply("ABACK", &guess_result![Y X G X X]);
+        assert_eq!(keyboard.get('A'), Some(WordleGuess::Correct));
+        keyboard.apply("SALTY", &guess_result![X Y X X X]);
+        assert_eq!(keyboard.get('A'), Some(WordleGuess::Correct));
+
+        let expected: BTreeMap<_, _> = [
+            ('A', WordleGuess::Correct),
+            ('B', WordleGuess::Incorrect),
+            ('C', WordleGuess::Incorrect),
+            ('D', WordleGuess::Incorrect),
+            ('E', WordleGuess::Incorrect),
+            ('I', WordleGuess::Incorrect),
+            ('K', WordleGuess::Incorrect),
+            ('L', WordleGuess::Incorrect),
+            ('N', WordleGuess::Incorrect),
+            ('P', WordleGuess::Incorrect),
+            ('R', WordleGuess::Incorrect),
+            ('S', WordleGuess::Incorrect),
+            ('T', WordleGuess::Incorrect),
+            ('Y', WordleGuess::Incorrect),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(keyboard.colors, expected);
+
+        let history = [
+            (String::from("CRANE"), guess_result![X X Y X X]),
+            (String::from("PLAID"), guess_result![X X G X X]),
+            (String::from("ABACK"), guess_result![Y X G X X]),
+            (String::from("SALTY"), guess_result![X Y X X X]),
+        ];
+        let state = keyboard_state(&history, KeyboardPolicy::Strongest);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn duplicate_letter_uses_best_in_guess() {
+        let history = [(String::from("SPEED"), guess_result![X X G X X])];
+
+        let keyboard = keyboard_state(&history, KeyboardPolicy::Latest);
+        assert_eq!(keyboard[&'E'], WordleGuess::Correct);
+    }
+}
@@ -7,18 +7,36 @@
 //!   information about a Wordle player
 //! - **`console_app`** *(requires: `player_db`)* - Methods
 //!   to run a game of Wordle as a console application
+//! - **`gzip`** *(requires: `console_app`)* - Loading
+//!   gzip-compressed dictionaries in the console application
+//! - **`serde`** *(requires: `player_db`)* - Saving players
+//!   to and loading players from JSON
 //!
 //! Author: Benjamin Hall
 
 #[cfg(feature = "console_app")]
 pub mod console_app;
+pub mod game;
+pub mod keyboard;
+pub mod normalize;
 #[cfg(feature = "player_db")]
 pub mod players;
+pub mod share;
+pub mod solver;
+pub mod word_list;
 
-use std::{borrow::Borrow, fmt};
+use std::{borrow::Borrow, cmp::Ordering, collections::HashSet, fmt, hash::BuildHasher};
+
+use self::{normalize::Normalizer, word_list::WordList};
+
+/// The number of guesses a player has to find the answer.
+pub const MAX_GUESSES: usize = 6;
+
+/// The number of letters in a word.
+pub const WORD_LEN: usize = 5;
 
 /// Possible guess results for a letter in a game of Wordle.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum WordleGuess {
     /// Green ("G"), the letter is in the word at that position
     Correct,
@@ -28,6 +46,40 @@ pub enum WordleGuess {
     Incorrect,
 }
 
+impl WordleGuess {
+    /// Ranks how much a guess result reveals about the answer.
+    const fn rank(self) -> u8 {
+        match self {
+            Self::Incorrect => 0,
+            Self::Present => 1,
+            Self::Correct => 2,
+        }
+    }
+}
+
+/// Guess results are ordered by how close the letter is to the
+/// answer: Incorrect < Present < Correct.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::WordleGuess;
+/// assert!(WordleGuess::Incorrect < WordleGuess::Present);
+/// assert!(WordleGuess::Present < WordleGuess::Correct);
+/// ```
+impl Ord for WordleGuess {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for WordleGuess {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for WordleGuess {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -38,18 +90,191 @@ impl fmt::Display for WordleGuess {
     }
 }
 
+/// Possible errors in a game of Wordle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordleError {
+    /// The guess is not the same length as the answer
+    LengthMismatch {
+        /// Length of the answer
+        expected: usize,
+        /// Length of the guess
+        found: usize,
+    },
+    /// The guess contains a character that is not a letter
+    /// of the alphabet being played
+    InvalidCharacter {
+        /// Position of the character in the guess
+        position: usize,
+        /// The character that is not a letter
+        found: char,
+    },
+    /// The words in a dictionary are not all the same length
+    MixedLengths {
+        /// Length of most of the words in the dictionary
+        length: usize,
+        /// Words that are not that length, in sorted order
+        offending: Vec<String>,
+    },
+    /// A player's current win streak is longer than their
+    /// maximum win streak
+    StreakExceedsMax {
+        /// Current win streak of the player
+        current: usize,
+        /// Maximum win streak of the player
+        max: usize,
+    },
+    /// A player has won more games than they have played
+    WinsExceedGames {
+        /// Number of games won, from the guess distribution
+        wins: usize,
+        /// Number of words played
+        games: usize,
+    },
+    /// A player's maximum win streak is longer than
+    /// the number of games they have won
+    StreakExceedsWins {
+        /// Maximum win streak of the player
+        streak: usize,
+        /// Number of games won, from the guess distribution
+        wins: usize,
+    },
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "guess has {found} letters, expected {expected}")
+            }
+            Self::InvalidCharacter { position, found } => {
+                write!(
+                    f,
+                    "guess has invalid character {found:?} at position {}",
+                    position + 1
+                )
+            }
+            Self::MixedLengths { length, offending } => {
+                write!(
+                    f,
+                    "dictionary words must all be {length} letters, found: {}",
+                    offending.join(", ")
+                )
+            }
+            Self::StreakExceedsMax { current, max } => {
+                write!(
+                    f,
+                    "current win streak {current} exceeds maximum win streak {max}"
+                )
+            }
+            Self::WinsExceedGames { wins, games } => {
+                write!(f, "{wins} games won but only {games} games played")
+            }
+            Self::StreakExceedsWins { streak, wins } => {
+                write!(f, "maximum win streak {streak} exceeds {wins} games won")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
+
+/// Possible reasons a guess is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    /// The guess does not have the right number of letters
+    WrongLength {
+        /// Number of letters a guess must have
+        expected: usize,
+        /// Number of letters in the guess
+        found: usize,
+    },
+    /// The guess contains something other than letters
+    NotAlphabetic,
+    /// The guess is not a word in the dictionary
+    NotInDictionary,
+    /// The game is already over, so no more guesses can be made
+    GameOver,
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WrongLength { expected, .. } => write!(f, "guess must be {expected} letters"),
+            Self::NotAlphabetic => write!(f, "guess must only contain letters"),
+            Self::NotInDictionary => write!(f, "guess must be a word in the dictionary"),
+            Self::GameOver => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
 /// Stores information about an answer to a game of Wordle.
 ///
 /// A game of Wordle has a target word. The guessing algorithm
 /// uses preprocessing so it can run in linear time. This results
-/// in an array containing the counts of each letter.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// in the counts of each letter, where the letters A to Z are
+/// counted in an array, and any other letters in a list.
+///
+/// The [`Debug`](fmt::Debug) output of an answer hides the word
+/// so it cannot be spoiled by accidentally logging the answer.
+/// Use [`WordleAnswer::debug_reveal`] to see the full answer.
+#[derive(Clone, PartialEq, Eq)]
 pub struct WordleAnswer<S>
 where
     S: Borrow<str>,
 {
     word: S,
-    letter_counts: [u8; 26],
+    letter_counts: LetterCounts,
+}
+
+/// Counts of each letter in a word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LetterCounts {
+    /* counts of the letters A to Z, which need no allocation */
+    ascii: [u8; 26],
+    /* counts of any other letters */
+    other: Vec<(char, u8)>,
+}
+
+impl LetterCounts {
+    /// Counts the number of each letter in a word.
+    fn new(word: &str) -> Self {
+        let mut letter_counts = Self {
+            ascii: [0; 26],
+            other: Vec::new(),
+        };
+        for c in word.chars() {
+            match letter_counts.count_mut(c) {
+                Some(count) => *count += 1,
+                None => letter_counts.other.push((c, 1)),
+            }
+        }
+        letter_counts
+    }
+
+    /// Gets the count of a letter, or None if a letter
+    /// other than A to Z was not counted.
+    fn count_mut(&mut self, c: char) -> Option<&mut u8> {
+        if c.is_ascii_uppercase() {
+            Some(&mut self.ascii[c as usize - 'A' as usize])
+        } else {
+            self.other
+                .iter_mut()
+                .find(|(letter, _)| *letter == c)
+                .map(|(_, count)| count)
+        }
+    }
+}
+
+impl<S> fmt::Debug for WordleAnswer<S>
+where
+    S: Borrow<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hidden_word = "*".repeat(self.word.borrow().chars().count());
+        write!(f, "WordleAnswer({hidden_word})")
+    }
 }
 
 /// Creates an array representing the result
@@ -95,10 +320,7 @@ where
     #[inline]
     #[must_use]
     pub fn new(word: S) -> Self {
-        let mut letter_counts = [0; 26];
-        for c in word.borrow().chars() {
-            letter_counts[c as usize - 'A' as usize] += 1;
-        }
+        let letter_counts = LetterCounts::new(word.borrow());
         Self {
             word,
             letter_counts,
@@ -122,6 +344,26 @@ where
         self.word.borrow()
     }
 
+    /// Formats this answer for debugging without hiding the word.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::WordleAnswer;
+    /// let answer = WordleAnswer::new("TRACE");
+    /// assert_eq!(format!("{answer:?}"), "WordleAnswer(*****)");
+    /// assert!(answer.debug_reveal().contains("TRACE"));
+    /// ```
+    #[must_use]
+    pub fn debug_reveal(&self) -> String {
+        format!(
+            "WordleAnswer {{ word: {:?}, letter_counts: {:?} }}",
+            self.word.borrow(),
+            self.letter_counts
+        )
+    }
+
     /// Calculates the correctness of a guess.
     ///
     /// This function returns an array containing the correctness
@@ -163,10 +405,24 @@ where
     /// let no_yellow = answer.check_guess("AADDD");
     /// assert_eq!(no_yellow, guess_result![X G X X X]);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the guess is not the same length
+    /// as the answer. In release builds, any letters past the end of
+    /// the answer are ignored, and any missing letters are Incorrect.
+    /// Use [`WordleAnswer::try_check_guess`] to check the length, or
+    /// [`WordleAnswer::try_check_guess_with`] to also check the letters.
     #[must_use]
-    pub fn check_guess(&self, guess: &str) -> [WordleGuess; 5] {
-        let mut colors = [WordleGuess::Incorrect; 5];
-        let mut letter_counts = self.letter_counts;
+    pub fn check_guess(&self, guess: &str) -> [WordleGuess; WORD_LEN] {
+        debug_assert_eq!(
+            guess.chars().count(),
+            self.word.borrow().chars().count(),
+            "guess must be the same length as the answer"
+        );
+
+        let mut colors = [WordleGuess::Incorrect; WORD_LEN];
+        let mut letter_counts = self.letter_counts.clone();
 
         /* first check for green letters */
         for ((a, g), color) in self
@@ -177,7 +433,9 @@ where
             .zip(colors.iter_mut())
         {
             if a == g {
-                letter_counts[g as usize - 'A' as usize] -= 1;
+                if let Some(count) = letter_counts.count_mut(g) {
+                    *count -= 1;
+                }
                 *color = WordleGuess::Correct;
             }
         }
@@ -186,16 +444,430 @@ where
         for (g, color) in guess.chars().zip(colors.iter_mut()) {
             if *color == WordleGuess::Incorrect {
                 /* letter has not yet been checked */
-                if letter_counts[g as usize - 'A' as usize] > 0 {
+                if let Some(count) = letter_counts.count_mut(g).filter(|count| **count > 0) {
                     /* letter in word but not this position */
                     *color = WordleGuess::Present;
-                    letter_counts[g as usize - 'A' as usize] -= 1;
+                    *count -= 1;
                 }
             }
         }
 
         colors
     }
+
+    /// Calculates the correctness of a guess, checking that
+    /// the guess is the same length as the answer.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the guess is not
+    /// the same length as the answer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{guess_result, WordleAnswer, WordleError};
+    /// let answer = WordleAnswer::new("TRACE");
+    /// assert_eq!(answer.try_check_guess("TRACE"), Ok(guess_result![G G G G G]));
+    /// assert_eq!(
+    ///     answer.try_check_guess("TRACES"),
+    ///     Err(WordleError::LengthMismatch { expected: 5, found: 6 })
+    /// );
+    /// ```
+    pub fn try_check_guess(&self, guess: &str) -> Result<[WordleGuess; WORD_LEN], WordleError> {
+        let expected = self.word.borrow().chars().count();
+        let found = guess.chars().count();
+        if expected != found {
+            return Err(WordleError::LengthMismatch { expected, found });
+        }
+        Ok(self.check_guess(guess))
+    }
+
+    /// Calculates the correctness of a guess, checking that the
+    /// guess is the same length as the answer and only contains
+    /// letters of the alphabet used by a normalizer.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the guess is not the
+    /// same length as the answer, or if any character of the guess
+    /// is not a letter according to [`Normalizer::is_letter`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{guess_result, normalize::AsciiUppercase, WordleAnswer, WordleError};
+    /// let answer = WordleAnswer::new("TRACE");
+    /// assert_eq!(
+    ///     answer.try_check_guess_with("TRACE", &AsciiUppercase),
+    ///     Ok(guess_result![G G G G G])
+    /// );
+    /// assert_eq!(
+    ///     answer.try_check_guess_with("TRAÇE", &AsciiUppercase),
+    ///     Err(WordleError::InvalidCharacter { position: 3, found: 'Ç' })
+    /// );
+    /// ```
+    pub fn try_check_guess_with(
+        &self,
+        guess: &str,
+        normalizer: &impl Normalizer,
+    ) -> Result<[WordleGuess; WORD_LEN], WordleError> {
+        let expected = self.word.borrow().chars().count();
+        let found = guess.chars().count();
+        if expected != found {
+            return Err(WordleError::LengthMismatch { expected, found });
+        }
+        if let Some((position, found)) = guess
+            .chars()
+            .enumerate()
+            .find(|&(_, c)| !normalizer.is_letter(c))
+        {
+            return Err(WordleError::InvalidCharacter { position, found });
+        }
+        Ok(self.check_guess(guess))
+    }
+
+    /// Shows the answer with the letters that are not known masked.
+    ///
+    /// Each letter of the answer is shown if the same position in
+    /// `known` is true, and is replaced with an underscore otherwise.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::WordleAnswer;
+    /// let answer = WordleAnswer::new("TRACE");
+    /// assert_eq!(answer.reveal_partial(&[true, false, false, true, false]), "T__C_");
+    /// ```
+    #[must_use]
+    pub fn reveal_partial(&self, known: &[bool; WORD_LEN]) -> String {
+        self.word
+            .borrow()
+            .chars()
+            .zip(known)
+            .map(|(c, &known)| if known { c } else { '_' })
+            .collect()
+    }
+
+    /// Chooses a letter of the answer to reveal as a hint.
+    ///
+    /// The leftmost position that is not [`WordleGuess::Correct`]
+    /// in `already_known` is chosen, along with the letter of the
+    /// answer in that position. If every position is already
+    /// known, this function returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{guess_result, WordleAnswer};
+    /// let answer = WordleAnswer::new("TRACE");
+    /// assert_eq!(answer.reveal_hint(&guess_result![G G X Y G]), Some((2, 'A')));
+    /// assert_eq!(answer.reveal_hint(&guess_result![G G G G G]), None);
+    /// ```
+    #[must_use]
+    pub fn reveal_hint(&self, already_known: &[WordleGuess; WORD_LEN]) -> Option<(usize, char)> {
+        self.word
+            .borrow()
+            .chars()
+            .zip(already_known)
+            .enumerate()
+            .find(|(_, (_, &known))| known != WordleGuess::Correct)
+            .map(|(pos, (letter, _))| (pos, letter))
+    }
+
+    /// Calculates the correctness of a single letter of a guess.
+    ///
+    /// The color of one letter can depend on the rest of the guess
+    /// when letters are repeated, so this function checks the whole
+    /// guess using [`WordleAnswer::check_guess`] and returns the
+    /// color at position `pos`. To get the color of every letter,
+    /// call [`WordleAnswer::check_guess`] once instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is not less than [`WORD_LEN`], and in the
+    /// same cases as [`WordleAnswer::check_guess`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{WordleAnswer, WordleGuess};
+    /// let answer = WordleAnswer::new("TRACE");
+    /// assert_eq!(answer.check_position("CRATE", 0), WordleGuess::Present);
+    /// assert_eq!(answer.check_position("CRATE", 1), WordleGuess::Correct);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn check_position(&self, guess: &str, pos: usize) -> WordleGuess {
+        self.check_guess(guess)[pos]
+    }
+}
+
+impl WordleAnswer<String> {
+    /// Creates a new Wordle answer, normalizing the word first.
+    ///
+    /// Guesses checked against this answer should be normalized
+    /// with the same normalizer, such as by [`validate_guess_with`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{guess_result, normalize::AsciiUppercase, WordleAnswer};
+    /// let answer = WordleAnswer::normalized("trace", &AsciiUppercase);
+    /// assert_eq!(answer.get_word(), "TRACE");
+    /// ```
+    #[must_use]
+    pub fn normalized(word: &str, normalizer: &impl Normalizer) -> Self {
+        Self::new(normalizer.normalize(word))
+    }
+}
+
+/// Contains the result of a finished game of Wordle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameResult {
+    /// The answer to the game
+    pub answer: String,
+    /// All guesses made, along with their colors
+    pub history: Vec<(String, [WordleGuess; WORD_LEN])>,
+    /// Number of guesses used to find the answer,
+    /// or None if the answer was not found
+    pub num_guesses: Option<usize>,
+}
+
+impl GameResult {
+    /// Gets the colors of each guess made.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::{guess_result, GameResult};
+    /// let result = GameResult {
+    ///     answer: String::from("TRACE"),
+    ///     history: vec![(String::from("TRACE"), guess_result![G G G G G])],
+    ///     num_guesses: Some(1),
+    /// };
+    /// assert_eq!(result.rows(), [guess_result![G G G G G]]);
+    /// ```
+    #[must_use]
+    pub fn rows(&self) -> Vec<[WordleGuess; WORD_LEN]> {
+        self.history.iter().map(|(_, colors)| *colors).collect()
+    }
+}
+
+/// Pairs each letter of each guess with its color.
+///
+/// Each guess in the history becomes one row, with one
+/// entry for each letter of the guess.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, WordleGuess};
+/// let history = [(String::from("TRACE"), guess_result![G G G G G])];
+/// let rows = wordle::detailed_rows(&history);
+/// assert_eq!(rows[0][0], ('T', WordleGuess::Correct));
+/// ```
+#[must_use]
+pub fn detailed_rows(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+) -> Vec<Vec<(char, WordleGuess)>> {
+    history
+        .iter()
+        .map(|(guess, colors)| guess.chars().zip(*colors).collect())
+        .collect()
+}
+
+/// Checks that every word in a dictionary is the same length.
+///
+/// Lengths are counted in characters. On success, the length of
+/// the words is returned, or 0 if the dictionary is empty.
+///
+/// # Errors
+///
+/// This function will return an error if the words are not all
+/// the same length. The error lists every word that is not the
+/// length of most of the words, where a tie goes to the shorter length.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::WordleError;
+/// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+/// assert_eq!(wordle::assert_uniform_length(&dictionary), Ok(5));
+///
+/// let dictionary = HashSet::from([String::from("TRACE"), String::from("TRACES")]);
+/// assert_eq!(
+///     wordle::assert_uniform_length(&dictionary),
+///     Err(WordleError::MixedLengths {
+///         length: 5,
+///         offending: vec![String::from("TRACES")]
+///     })
+/// );
+/// ```
+pub fn assert_uniform_length(
+    dictionary: &HashSet<String, impl BuildHasher>,
+) -> Result<usize, WordleError> {
+    let mut counts = std::collections::BTreeMap::new();
+    for word in dictionary {
+        *counts.entry(word.chars().count()).or_insert(0_usize) += 1;
+    }
+
+    /* the last maximum is kept, so go from longest to shortest to favour shorter words */
+    let Some(length) = counts
+        .iter()
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .map(|(&length, _)| length)
+    else {
+        return Ok(0);
+    };
+    if counts.len() == 1 {
+        return Ok(length);
+    }
+
+    let mut offending: Vec<_> = dictionary
+        .iter()
+        .filter(|word| word.chars().count() != length)
+        .cloned()
+        .collect();
+    offending.sort_unstable();
+    Err(WordleError::MixedLengths { length, offending })
+}
+
+/// Calculates the correctness of a guess against many answers.
+///
+/// This function is equivalent to calling [`WordleAnswer::check_guess`]
+/// on each answer, but allows the answers to be preprocessed once and
+/// reused across many guesses.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, WordleAnswer};
+/// let answers = [WordleAnswer::new("TRACE"), WordleAnswer::new("BLIND")];
+/// let results = wordle::check_many(&answers, "CRANE");
+/// assert_eq!(
+///     results,
+///     [guess_result![Y G G X G], guess_result![X X X G X]]
+/// );
+/// ```
+#[must_use]
+pub fn check_many<S>(answers: &[WordleAnswer<S>], guess: &str) -> Vec<[WordleGuess; WORD_LEN]>
+where
+    S: Borrow<str>,
+{
+    answers
+        .iter()
+        .map(|answer| answer.check_guess(guess))
+        .collect()
+}
+
+/// Checks whether a guess is valid without playing it.
+///
+/// A valid guess has `word_len` letters, contains only letters,
+/// and is in the dictionary. The guess should already be in the
+/// same case as the dictionary.
+///
+/// The length of the guess is counted in characters rather than
+/// bytes, so a guess with letters outside of A-Z is rejected for
+/// not being alphabetic instead of for its length. Answers only
+/// use the letters A-Z, so those letters could never match.
+///
+/// # Errors
+///
+/// This function will return an error describing the first
+/// check that the guess fails, in the order above.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::{GuessError, WORD_LEN};
+/// let dictionary = HashSet::from([String::from("TRACE")]);
+/// assert_eq!(wordle::validate_guess("TRACE", &dictionary, WORD_LEN), Ok(()));
+/// assert_eq!(
+///     wordle::validate_guess("CRATE", &dictionary, WORD_LEN),
+///     Err(GuessError::NotInDictionary)
+/// );
+/// ```
+pub fn validate_guess(
+    guess: &str,
+    dictionary: &impl WordList,
+    word_len: usize,
+) -> Result<(), GuessError> {
+    check_guess_with(guess, dictionary, word_len, |c| c.is_ascii_alphabetic())
+}
+
+/// Normalizes a guess and checks that it is valid.
+///
+/// This works the same as [`validate_guess`], except that the
+/// guess is normalized first, and the normalizer decides which
+/// characters are letters. On success, the normalized guess
+/// is returned.
+///
+/// # Errors
+///
+/// This function will return an error describing the first
+/// check that the normalized guess fails.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::{normalize::AsciiUppercase, WORD_LEN};
+/// let dictionary = HashSet::from([String::from("TRACE")]);
+/// assert_eq!(
+///     wordle::validate_guess_with("trace", &dictionary, WORD_LEN, &AsciiUppercase),
+///     Ok(String::from("TRACE"))
+/// );
+/// ```
+pub fn validate_guess_with(
+    guess: &str,
+    dictionary: &impl WordList,
+    word_len: usize,
+    normalizer: &impl Normalizer,
+) -> Result<String, GuessError> {
+    let guess = normalizer.normalize(guess);
+    check_guess_with(&guess, dictionary, word_len, |c| normalizer.is_letter(c))?;
+    Ok(guess)
+}
+
+/// Checks whether a guess is valid, using `is_letter` to
+/// decide which characters are letters.
+///
+/// The checks are made in the order described by [`validate_guess`].
+fn check_guess_with(
+    guess: &str,
+    dictionary: &impl WordList,
+    word_len: usize,
+    is_letter: impl Fn(char) -> bool,
+) -> Result<(), GuessError> {
+    let found = guess.chars().count();
+    if found != word_len {
+        Err(GuessError::WrongLength {
+            expected: word_len,
+            found,
+        })
+    } else if !guess.chars().all(is_letter) {
+        Err(GuessError::NotAlphabetic)
+    } else if !dictionary.is_valid(guess) {
+        Err(GuessError::NotInDictionary)
+    } else {
+        Ok(())
+    }
 }
 
 /// Contains all the possible messages
@@ -205,7 +877,7 @@ where
 /// guesses (starting at 1), then
 /// `WIN_MESSAGES[n - 1]` is the message
 /// that should be displayed.
-pub const WIN_MESSAGES: [&str; 6] = [
+pub const WIN_MESSAGES: [&str; MAX_GUESSES] = [
     "Genius",
     "Magnificent",
     "Impressive",
@@ -214,10 +886,127 @@ pub const WIN_MESSAGES: [&str; 6] = [
     "Phew",
 ];
 
+/// Contains the messages for win streak milestones.
+///
+/// Each entry contains the length of the win streak
+/// and the message that should be displayed when the
+/// player's current win streak reaches that length.
+pub const STREAK_MESSAGES: [(usize, &str); 4] = [
+    (5, "You're on a roll, 5 wins in a row"),
+    (10, "Unstoppable, 10 wins in a row"),
+    (25, "Legendary, 25 wins in a row"),
+    (50, "Wordle master, 50 wins in a row"),
+];
+
+/// Gets the message for a win streak milestone.
+///
+/// If the win streak is not a milestone in
+/// [`STREAK_MESSAGES`], this function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::streak_message;
+/// assert!(streak_message(5).is_some());
+/// assert!(streak_message(6).is_none());
+/// ```
+#[must_use]
+pub fn streak_message(streak: usize) -> Option<&'static str> {
+    STREAK_MESSAGES
+        .iter()
+        .find(|(milestone, _)| *milestone == streak)
+        .map(|(_, message)| *message)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn hint_skips_known_positions() {
+        let answer = WordleAnswer::new("TRACE");
+        assert_eq!(
+            answer.reveal_hint(&guess_result![X X X X X]),
+            Some((0, 'T'))
+        );
+        assert_eq!(
+            answer.reveal_hint(&guess_result![G G Y G X]),
+            Some((2, 'A'))
+        );
+        assert_eq!(
+            answer.reveal_hint(&guess_result![G G G G X]),
+            Some((4, 'E'))
+        );
+        assert_eq!(answer.reveal_hint(&guess_result![G G G G G]), None);
+    }
+
+    #[test]
+    fn detailed_rows_pair_letters_with_colors() {
+        use WordleGuess::{Correct as G, Incorrect as X, Present as Y};
+
+        let answer = WordleAnswer::new("TRACE");
+        let history: Vec<_> = ["BLIND", "CRATE"]
+            .into_iter()
+            .map(|guess| (guess.to_owned(), answer.check_guess(guess)))
+            .collect();
+
+        assert_eq!(
+            detailed_rows(&history),
+            [
+                vec![('B', X), ('L', X), ('I', X), ('N', X), ('D', X)],
+                vec![('C', Y), ('R', G), ('A', G), ('T', Y), ('E', G)],
+            ]
+        );
+    }
+
+    #[test]
+    fn identity_normalizer_keeps_script() {
+        use normalize::Identity;
+
+        let dictionary = HashSet::from([String::from("さくらんぼ"), String::from("くらさんぼ")]);
+        let answer = WordleAnswer::normalized("さくらんぼ", &Identity);
+
+        let guess = validate_guess_with("くらさんぼ", &dictionary, WORD_LEN, &Identity).unwrap();
+        assert_eq!(answer.check_guess(&guess), guess_result![Y Y Y G G]);
+        assert_eq!(
+            validate_guess_with("さくら", &dictionary, WORD_LEN, &Identity),
+            Err(GuessError::WrongLength {
+                expected: WORD_LEN,
+                found: 3
+            })
+        );
+
+        /* identity keeps case, so a lowercase guess does not match */
+        let answer = WordleAnswer::normalized("TRACE", &Identity);
+        assert_eq!(answer.check_guess("trace"), guess_result![X X X X X]);
+    }
+
+    #[test]
+    fn partial_answer_is_masked() {
+        let answer = WordleAnswer::new("SPEED");
+        assert_eq!(
+            answer.reveal_partial(&[false, true, false, false, true]),
+            "_P__D"
+        );
+        assert_eq!(answer.reveal_partial(&[true; WORD_LEN]), "SPEED");
+        assert_eq!(answer.reveal_partial(&[false; WORD_LEN]), "_____");
+    }
+
+    #[test]
+    fn check_position_handles_duplicates() {
+        /* only one of the guessed A's can be yellow */
+        let answer = WordleAnswer::new("BACCC");
+        assert_eq!(answer.check_position("AADDD", 0), WordleGuess::Incorrect);
+        assert_eq!(answer.check_position("AADDD", 1), WordleGuess::Correct);
+
+        let answer = WordleAnswer::new("AZZAZ");
+        let colors = answer.check_guess("AAABB");
+        for (pos, color) in colors.into_iter().enumerate() {
+            assert_eq!(answer.check_position("AAABB", pos), color);
+        }
+    }
+
     #[test]
     fn all_green() {
         let answer = WordleAnswer::new("ABCDE");
@@ -265,4 +1054,184 @@ mod test {
         let answer = WordleAnswer::new("BACCC");
         assert_eq!(answer.check_guess("AADDD"), guess_result![X G X X X]);
     }
+
+    #[test]
+    fn check_many_matches_check_guess() {
+        let words = ["TRACE", "BLIND", "AABBB", "AZZAZ", "BACCC"];
+        let answers: Vec<_> = words.iter().map(|&w| WordleAnswer::new(w)).collect();
+
+        for guess in ["CRANE", "AADDD", "CAACC", "AAABB"] {
+            let expected: Vec<_> = words
+                .iter()
+                .map(|&w| WordleAnswer::new(w).check_guess(guess))
+                .collect();
+            assert_eq!(check_many(&answers, guess), expected);
+        }
+    }
+
+    #[test]
+    fn debug_hides_word() {
+        let answer = WordleAnswer::new("TRACE");
+        let debug = format!("{answer:?}");
+        assert!(!debug.contains("TRACE"));
+        assert_eq!(debug, "WordleAnswer(*****)");
+        assert!(!format!("{answer:#?}").contains("TRACE"));
+    }
+
+    #[test]
+    fn debug_reveal_shows_word() {
+        let answer = WordleAnswer::new(String::from("TRACE"));
+        assert!(answer.debug_reveal().contains("\"TRACE\""));
+    }
+
+    #[test]
+    fn streak_milestones() {
+        assert_eq!(streak_message(10), Some(STREAK_MESSAGES[1].1));
+        assert_eq!(streak_message(0), None);
+        assert_eq!(streak_message(11), None);
+    }
+
+    #[test]
+    fn out_of_alphabet_guess_is_rejected() {
+        use normalize::{AsciiUppercase, Identity};
+
+        let answer = WordleAnswer::new("TRACE");
+        /* a Cyrillic letter that looks like E */
+        assert_eq!(
+            answer.try_check_guess_with("TRAC\u{415}", &AsciiUppercase),
+            Err(WordleError::InvalidCharacter {
+                position: 4,
+                found: '\u{415}'
+            })
+        );
+        assert_eq!(
+            answer.try_check_guess_with("CR4TE", &Identity),
+            Err(WordleError::InvalidCharacter {
+                position: 2,
+                found: '4'
+            })
+        );
+        /* the length is checked before the letters */
+        assert_eq!(
+            answer.try_check_guess_with("TRAC", &AsciiUppercase),
+            Err(WordleError::LengthMismatch {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(
+            answer.try_check_guess_with("CRATE", &AsciiUppercase),
+            Ok(guess_result![Y G G Y G])
+        );
+    }
+
+    #[test]
+    fn mixed_length_dictionary() {
+        let dictionary: HashSet<_> = ["TRACE", "CRATE", "BLIND", "TRACES", "CRAT"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        assert_eq!(
+            assert_uniform_length(&dictionary),
+            Err(WordleError::MixedLengths {
+                length: 5,
+                offending: vec![String::from("CRAT"), String::from("TRACES")]
+            })
+        );
+
+        /* a tie goes to the shorter words */
+        let dictionary: HashSet<_> = ["TRACE", "TRACES"].into_iter().map(str::to_owned).collect();
+        assert_eq!(
+            assert_uniform_length(&dictionary),
+            Err(WordleError::MixedLengths {
+                length: 5,
+                offending: vec![String::from("TRACES")]
+            })
+        );
+
+        assert_eq!(assert_uniform_length(&HashSet::<String>::new()), Ok(0));
+    }
+
+    #[test]
+    fn try_check_guess_length() {
+        let answer = WordleAnswer::new("TRACE");
+        assert_eq!(
+            answer.try_check_guess("CRATE"),
+            Ok(guess_result![Y G G Y G])
+        );
+        assert_eq!(
+            answer.try_check_guess("CRATES"),
+            Err(WordleError::LengthMismatch {
+                expected: 5,
+                found: 6
+            })
+        );
+        assert_eq!(
+            answer.try_check_guess("CRAT"),
+            Err(WordleError::LengthMismatch {
+                expected: 5,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "guess must be the same length as the answer")]
+    fn long_guess_panics_in_debug() {
+        let answer = WordleAnswer::new("TRACE");
+        _ = answer.check_guess("CRATES");
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn long_guess_truncated_in_release() {
+        let answer = WordleAnswer::new("TRACE");
+        assert_eq!(answer.check_guess("CRATES"), guess_result![Y G G Y G]);
+    }
+
+    #[test]
+    fn constants_size_arrays() {
+        let colors: [WordleGuess; WORD_LEN] = [WordleGuess::Correct; WORD_LEN];
+        assert_eq!(WordleAnswer::new("TRACE").check_guess("TRACE"), colors);
+
+        let distribution = [0usize; MAX_GUESSES];
+        assert_eq!(distribution.len(), WIN_MESSAGES.len());
+    }
+
+    #[test]
+    fn valid_guess_is_ok() {
+        let dictionary = HashSet::from([String::from("TRACE")]);
+        assert_eq!(validate_guess("TRACE", &dictionary, WORD_LEN), Ok(()));
+    }
+
+    #[test]
+    fn wrong_length_guess() {
+        let dictionary = HashSet::from([String::from("TRACE")]);
+        assert_eq!(
+            validate_guess("TRACES", &dictionary, WORD_LEN),
+            Err(GuessError::WrongLength {
+                expected: 5,
+                found: 6
+            })
+        );
+    }
+
+    #[test]
+    fn not_alphabetic_guess() {
+        let dictionary = HashSet::from([String::from("TRACE")]);
+        assert_eq!(
+            validate_guess("TR4CE", &dictionary, WORD_LEN),
+            Err(GuessError::NotAlphabetic)
+        );
+    }
+
+    #[test]
+    fn not_in_dictionary_guess() {
+        let dictionary = HashSet::from([String::from("TRACE")]);
+        assert_eq!(
+            validate_guess("CRATE", &dictionary, WORD_LEN),
+            Err(GuessError::NotInDictionary)
+        );
+    }
 }
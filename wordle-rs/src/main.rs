@@ -4,28 +4,66 @@
 //! Author: Benjamin Hall
 
 use std::{
-    collections::{BTreeSet, HashSet},
+    collections::BTreeSet,
     env,
     fs::{File, OpenOptions},
     io::{self, BufReader, Read, Write},
 };
 
+use wordle::{
+    console_app::{game::GameOptions, main_menu},
+    players,
+    share::EmojiTheme,
+};
+
+/// Command-line arguments to the Wordle program.
+struct Args {
+    /// Name of the dictionary file
+    dict_file_name: String,
+    /// Name of the file of words only allowed as guesses
+    guesses_file_name: Option<String>,
+    /// Options for each game of Wordle
+    options: GameOptions,
+    /// Seed for choosing words, if the session should be reproducible
+    seed: Option<u64>,
+    /// User to log in without asking for a username
+    username: Option<String>,
+}
+
 /// Runs the Wordle program.
 ///
 /// The user must pass in the name of the dictionary
 /// file as a command-line argument to the program.
 fn main() {
-    let args: Vec<_> = env::args().collect();
-    if args.len() != 2 {
-        /* invalid number of arguments, print a help message */
-        let mut lock = io::stdout().lock();
-        writeln!(lock, "Wordle").unwrap();
-        writeln!(lock, "Author: Benjamin Hall").unwrap();
-        writeln!(lock, "Usage: ./wordle-rs [dictionary file name]").unwrap();
-        return;
-    }
+    let args = match parse_args(env::args().skip(1)) {
+        Some(args) => args,
+        None => {
+            /* invalid arguments, print a help message */
+            let mut lock = io::stdout().lock();
+            writeln!(lock, "Wordle").unwrap();
+            writeln!(lock, "Author: Benjamin Hall").unwrap();
+            writeln!(lock, "Usage: ./wordle-rs [options] [dictionary file name]").unwrap();
+            writeln!(lock, "Options:").unwrap();
+            writeln!(lock, "  --hints\tallow hints during a game").unwrap();
+            writeln!(lock, "  --suggest\tallow suggested guesses during a game").unwrap();
+            writeln!(lock, "  --board\tshow every guess after each guess").unwrap();
+            writeln!(lock, "  --clear\tshow the board on a cleared screen").unwrap();
+            writeln!(lock, "  --hard\trequire guesses to use all revealed hints").unwrap();
+            writeln!(lock, "  --resume\tsave unfinished games to resume later").unwrap();
+            writeln!(lock, "  --time\tshow how long each game took").unwrap();
+            writeln!(lock, "  --quiet\tonly show the result of each game").unwrap();
+            writeln!(lock, "  --no-repeat\task again after a repeated guess").unwrap();
+            writeln!(lock, "  --any-guess\taccept guesses not in the dictionary").unwrap();
+            writeln!(lock, "  --high-contrast\tuse orange and blue squares").unwrap();
+            writeln!(lock, "  --guesses FILE\talso accept the guesses in FILE").unwrap();
+            writeln!(lock, "  --seed N\tchoose words reproducibly using seed N").unwrap();
+            writeln!(lock, "  --user NAME\tlog in as NAME without asking").unwrap();
+            writeln!(lock, "  --recent\tlist the most recent users first").unwrap();
+            return;
+        }
+    };
 
-    let dict_file_name = args[1].as_str();
+    let dict_file_name = args.dict_file_name.as_str();
 
     /* make sure none of the inputs are empty */
     if dict_file_name.is_empty() {
@@ -33,19 +71,40 @@ fn main() {
         return;
     }
 
-    let dict_file = File::open(dict_file_name);
-    let dict_file_contents = match read_file(dict_file) {
-        Ok(dict_file_contents) => dict_file_contents,
-        Err(_) => {
-            println!("Error: could not read dictionary file");
+    let dictionary = match wordle::console_app::load_dictionary_with(dict_file_name, &args.options)
+    {
+        Ok(dictionary) => dictionary,
+        /* the message of an unsupported file already explains the error */
+        Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+            println!("{err}");
+            return;
+        }
+        Err(err) => {
+            println!("Error: could not read dictionary file: {err}");
             return;
         }
     };
 
+    let mut options = args.options;
+    if let Some(guesses_file_name) = args.guesses_file_name {
+        match wordle::console_app::load_dictionary_with(&guesses_file_name, &options) {
+            Ok(allowed_guesses) => options.allowed_guesses = allowed_guesses,
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                println!("{err}");
+                return;
+            }
+            Err(err) => {
+                println!("Error: could not read guesses file: {err}");
+                return;
+            }
+        }
+    }
+
     let usernames_file = OpenOptions::new()
         .read(true)
         .write(true)
         .create(true)
+        .truncate(false)
         .open(wordle::console_app::USERNAMES_FILENAME);
     let usernames_file_contents = match read_file(usernames_file) {
         Ok(usernames_file_contents) => usernames_file_contents,
@@ -55,20 +114,94 @@ fn main() {
         }
     };
 
-    let dictionary: HashSet<String> = dict_file_contents
-        .lines()
-        .filter(|s| s.len() == 5)
-        .map(str::to_uppercase)
-        .collect();
+    let recent_users_file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(wordle::console_app::RECENT_USERS_FILENAME);
+    let recent_users_file_contents = match read_file(recent_users_file) {
+        Ok(recent_users_file_contents) => recent_users_file_contents,
+        Err(_) => {
+            println!("Error: could not read recent user database");
+            return;
+        }
+    };
 
     let mut usernames: BTreeSet<String> =
         usernames_file_contents.lines().map(str::to_owned).collect();
 
-    wordle::console_app::run(&dictionary, &mut usernames);
+    let mut recent_users: Vec<String> = recent_users_file_contents
+        .lines()
+        .map(str::to_owned)
+        .collect();
+
+    if let Some(seed) = args.seed {
+        wordle::console_app::seed_rng(seed);
+    }
+
+    wordle::console_app::run(
+        &dictionary,
+        &mut usernames,
+        &mut recent_users,
+        args.username,
+        &options,
+    );
+}
+
+/// Parses the command-line arguments to the program.
+///
+/// Exactly one dictionary file name must be given, along
+/// with any number of options. If the arguments are invalid,
+/// this function returns None.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Option<Args> {
+    let mut dict_file_name = None;
+    let mut guesses_file_name = None;
+    let mut options = GameOptions::default();
+    let mut seed = None;
+    let mut username = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--hints" => options.allow_hints = true,
+            "--suggest" => options.allow_suggestions = true,
+            "--board" => options.show_board = true,
+            "--clear" => {
+                options.show_board = true;
+                options.clear_screen = true;
+            }
+            "--hard" => options.hard_mode = true,
+            "--resume" => options.allow_resume = true,
+            "--time" => options.show_timing = true,
+            "--quiet" => options.quiet = true,
+            "--no-repeat" => options.warn_repeat_guess = true,
+            "--any-guess" => options.validate_guesses = false,
+            "--high-contrast" => options.emoji_theme = EmojiTheme::HighContrast,
+            "--guesses" => guesses_file_name = Some(args.next()?),
+            /* the seed is given as the next argument */
+            "--seed" => seed = Some(args.next()?.parse().ok()?),
+            "--user" => username = Some(main_menu::normalize_username(&args.next()?)?),
+            "--recent" => options.sort_users_by_recency = true,
+            _ if arg.starts_with("--") => return None,
+            /* only one dictionary file may be given */
+            _ if dict_file_name.is_some() => return None,
+            _ => dict_file_name = Some(arg),
+        }
+    }
+
+    Some(Args {
+        dict_file_name: dict_file_name?,
+        guesses_file_name,
+        options,
+        seed,
+        username,
+    })
 }
 
 /// Loads data from a file into a String.
 ///
+/// A byte order mark at the start of the file is removed.
+///
 /// Any errors, such as the file not existing or not having
 /// read access, will be propagated up to the caller.
 ///
@@ -89,5 +222,37 @@ fn read_file(file: io::Result<File>) -> io::Result<String> {
     let mut buffer = String::new();
     let mut reader = BufReader::new(file);
     reader.read_to_string(&mut buffer)?;
-    Ok(buffer)
+    Ok(players::strip_bom(&buffer).to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parses the given command-line arguments.
+    fn parse(args: &[&str]) -> Option<Args> {
+        parse_args(args.iter().map(|&arg| arg.to_owned()))
+    }
+
+    #[test]
+    fn user_flag_is_parsed() {
+        let args = parse(&["--user", " User ", "dictionary.txt"]).unwrap();
+        assert_eq!(args.username.as_deref(), Some("user"));
+        assert_eq!(args.dict_file_name, "dictionary.txt");
+
+        /* the flag may come after the dictionary file */
+        let args = parse(&["dictionary.txt", "--user", "alice"]).unwrap();
+        assert_eq!(args.username.as_deref(), Some("alice"));
+
+        let args = parse(&["dictionary.txt"]).unwrap();
+        assert_eq!(args.username, None);
+    }
+
+    #[test]
+    fn invalid_user_flag_is_rejected() {
+        /* the username must not be empty */
+        assert!(parse(&["--user", "  ", "dictionary.txt"]).is_none());
+        /* the username must be given */
+        assert!(parse(&["dictionary.txt", "--user"]).is_none());
+    }
 }
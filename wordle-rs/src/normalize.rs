@@ -0,0 +1,69 @@
+//! This module provides methods to normalize words before
+//! they are compared in a game of Wordle, so that answers and
+//! guesses written differently are treated as the same word.
+//!
+//! Author: Benjamin Hall
+
+/// Converts a word into the form used to compare it with other words.
+///
+/// A normalizer is used for both answers and guesses, so they
+/// are always compared in the same form. See
+/// [`WordleAnswer::normalized`](crate::WordleAnswer::normalized)
+/// and [`validate_guess_with`](crate::validate_guess_with).
+pub trait Normalizer {
+    /// Converts a word into its normalized form.
+    fn normalize(&self, word: &str) -> String;
+
+    /// Checks whether a character is a letter that can be
+    /// used in a word, after normalizing.
+    ///
+    /// By default, any alphabetic character is a letter.
+    fn is_letter(&self, c: char) -> bool {
+        c.is_alphabetic()
+    }
+}
+
+/// Converts words to uppercase, where only the letters
+/// A to Z are allowed.
+///
+/// This is the normalization used by the rest of the crate.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::normalize::{AsciiUppercase, Normalizer};
+/// assert_eq!(AsciiUppercase.normalize("Trace"), "TRACE");
+/// assert!(!AsciiUppercase.is_letter('É'));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AsciiUppercase;
+
+impl Normalizer for AsciiUppercase {
+    fn normalize(&self, word: &str) -> String {
+        word.to_ascii_uppercase()
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_ascii_alphabetic()
+    }
+}
+
+/// Leaves words unchanged, for scripts without case.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::normalize::{Identity, Normalizer};
+/// assert_eq!(Identity.normalize("さくらんぼ"), "さくらんぼ");
+/// assert!(Identity.is_letter('さ'));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Identity;
+
+impl Normalizer for Identity {
+    fn normalize(&self, word: &str) -> String {
+        word.to_owned()
+    }
+}
@@ -17,7 +17,11 @@ pub struct Entry<S, T, V> {
 }
 
 /// Delimiter between the field name and data for database entries
-const DELIM: &str = ": ";
+pub const DELIM: &str = ": ";
+/// Delimiter between elements of a collection for database entries
+pub const ITEM_DELIM: &str = ",";
+/// Character used to escape delimiters and newlines in database entries
+pub const ESCAPE: char = '\\';
 
 impl<S, T, V> Entry<S, T, V> {
     /// Creates a new database entry with the
@@ -67,7 +71,35 @@ where
     /// ```
     #[must_use]
     pub fn from_line(line: &'a str, string_to_t: impl Fn(&'a str) -> T) -> Option<Self> {
-        let split_str = line.split_once(DELIM);
+        Self::from_line_with(line, DELIM, string_to_t)
+    }
+
+    /// Creates a simple database entry from a line of text
+    /// using a custom delimiter.
+    ///
+    /// The text will be split between field name and data
+    /// on the given delimiter. If the delimiter is not found,
+    /// then this function returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```ignore
+    /// # use std::convert::identity;
+    /// # use wordle::players::database::Entry;
+    /// let str_entry = Entry::from_line_with("String Test\tdata", "\t", identity);
+    /// assert_eq!(
+    ///     str_entry.unwrap(),
+    ///     Entry::new("String Test", "data")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_line_with(
+        line: &'a str,
+        delim: &str,
+        string_to_t: impl Fn(&'a str) -> T,
+    ) -> Option<Self> {
+        let split_str = line.split_once(delim);
         split_str.map(|(key, value)| Self::new(key.into(), string_to_t(value)))
     }
 
@@ -99,7 +131,40 @@ where
         line: &'a str,
         string_to_t: impl Fn(&'a str) -> Result<T, E>,
     ) -> Result<Option<Self>, E> {
-        let split_str = line.split_once(DELIM);
+        Self::try_from_line_with(line, DELIM, string_to_t)
+    }
+
+    /// Creates a simple database entry from a line of text where
+    /// parsing the data entry has the potential to fail, using a
+    /// custom delimiter.
+    ///
+    /// The text will be split between field name and data
+    /// on the given delimiter. If the delimiter is not found,
+    /// then this function returns None.
+    ///
+    /// If this function fails to parse the data section, it
+    /// will return the error to the caller.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```ignore
+    /// # use wordle::players::database::Entry;
+    /// # fn main() -> Result<(), std::num::ParseIntError> {
+    /// let int_entry = Entry::try_from_line_with("Int Test|3", "|", str::parse::<i32>)?;
+    /// assert_eq!(
+    ///     int_entry.unwrap(),
+    ///     Entry::new("Int Test", 3)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_line_with<E>(
+        line: &'a str,
+        delim: &str,
+        string_to_t: impl Fn(&'a str) -> Result<T, E>,
+    ) -> Result<Option<Self>, E> {
+        let split_str = line.split_once(delim);
         split_str
             .map(|(key, value)| {
                 let value = string_to_t(value)?;
@@ -147,9 +212,44 @@ where
     /// ```
     #[must_use]
     pub fn from_collection(line: &'a str, string_to_v: impl Fn(&'a str) -> V) -> Option<Self> {
-        let parsed_row = Entry::<&str, _, _>::from_line(line, identity);
+        Self::from_collection_with(line, DELIM, ITEM_DELIM, string_to_v)
+    }
+
+    /// Creates a database entry from a line of text where
+    /// the data field is a collection of elements, using
+    /// custom delimiters.
+    ///
+    /// The text will be split between field name and data
+    /// on the given delimiter. If the delimiter is not found,
+    /// then this function returns None. From there, elements
+    /// will be separated by the given item delimiter and added
+    /// to a collection.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```ignore
+    /// # use std::convert::identity;
+    /// # use wordle::players::database::Entry;
+    /// let str_vec_entry =
+    ///     Entry::from_collection_with("String Test\tdata1|data2", "\t", "|", identity);
+    /// assert_eq!(
+    ///     str_vec_entry.unwrap(),
+    ///     Entry::new("String Test", vec!["data1", "data2"])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_collection_with(
+        line: &'a str,
+        delim: &str,
+        item_delim: &str,
+        string_to_v: impl Fn(&'a str) -> V,
+    ) -> Option<Self> {
+        let parsed_row = Entry::<&str, _, _>::from_line_with(line, delim, identity);
         parsed_row.map(|parsed_row| {
-            let items = parsed_row.value.split(',').map(string_to_v).collect();
+            let items = split_items(parsed_row.value, item_delim)
+                .map(string_to_v)
+                .collect();
 
             Self::new(parsed_row.key.into(), items)
         })
@@ -195,12 +295,49 @@ where
         line: &'a str,
         string_to_v: impl Fn(&'a str) -> Result<V, E>,
     ) -> Result<Option<Self>, E> {
-        let parsed_row = Entry::<&str, _, _>::from_line(line, identity);
+        Self::try_from_collection_with(line, DELIM, ITEM_DELIM, string_to_v)
+    }
+
+    /// Creates a database entry from a line of text where
+    /// the data field is a collection of elements, and
+    /// parsing the data entry has the potential to fail,
+    /// using custom delimiters.
+    ///
+    /// The text will be split between field name and data
+    /// on the given delimiter. If the delimiter is not found,
+    /// then this function returns None. From there, elements
+    /// will be separated by the given item delimiter and added
+    /// to a collection.
+    ///
+    /// If at any point in time this function fails to parse
+    /// an element of the data section, it will return the error
+    /// to the caller.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```ignore
+    /// # use wordle::players::database::Entry;
+    /// # fn main() -> Result<(), std::num::ParseIntError> {
+    /// let int_vec_entry =
+    ///     Entry::try_from_collection_with("Int Test|4\t3", "|", "\t", str::parse::<i32>)?;
+    /// assert_eq!(
+    ///     int_vec_entry.unwrap(),
+    ///     Entry::new("Int Test", vec![4, 3])
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_collection_with<E>(
+        line: &'a str,
+        delim: &str,
+        item_delim: &str,
+        string_to_v: impl Fn(&'a str) -> Result<V, E>,
+    ) -> Result<Option<Self>, E> {
+        let parsed_row = Entry::<&str, _, _>::from_line_with(line, delim, identity);
         parsed_row
             .map(|parsed_row| {
-                let items = parsed_row
-                    .value
-                    .split(',')
+                let items = split_items(parsed_row.value, item_delim)
                     .map(string_to_v)
                     .collect::<Result<_, _>>()?;
 
@@ -209,3 +346,182 @@ where
             .map_or(Ok(None), |r| r.map(Some))
     }
 }
+
+/// Removes the byte order mark some editors add to the start
+/// of a UTF-8 file, if there is one.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::players;
+/// assert_eq!(players::strip_bom("\u{feff}user"), "user");
+/// assert_eq!(players::strip_bom("user"), "user");
+/// ```
+#[inline]
+#[must_use]
+pub fn strip_bom(contents: &str) -> &str {
+    contents.strip_prefix('\u{feff}').unwrap_or(contents)
+}
+
+/// Escapes a value so it can be written to a database entry.
+///
+/// The escape character, the item delimiter, and newlines are
+/// all preceded by the escape character, with newlines written
+/// as "n". The value can be restored using [`unescape`].
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```ignore
+/// # use wordle::players::database;
+/// assert_eq!(database::escape("A,B\nC"), "A\\,B\\nC");
+/// ```
+#[must_use]
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\n' => {
+                escaped.push(ESCAPE);
+                escaped.push('n');
+            }
+            c if c == ESCAPE || ITEM_DELIM.contains(c) => {
+                escaped.push(ESCAPE);
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Restores a value that was escaped using [`escape`].
+///
+/// An escape character at the end of the value is kept as-is.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```ignore
+/// # use wordle::players::database;
+/// assert_eq!(database::unescape("A\\,B\\nC"), "A,B\nC");
+/// ```
+#[must_use]
+pub fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != ESCAPE {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push(ESCAPE),
+        }
+    }
+    unescaped
+}
+
+/// Splits the data field of a collection entry into its elements.
+///
+/// An empty data field is an empty collection, rather than
+/// a collection with a single empty element. Delimiters
+/// preceded by the escape character do not split elements,
+/// and the elements are returned still escaped.
+fn split_items<'a: 'b, 'b>(
+    value: &'a str,
+    item_delim: &'b str,
+) -> impl Iterator<Item = &'a str> + 'b {
+    let mut rest = (!value.is_empty()).then_some(value);
+    std::iter::from_fn(move || {
+        let remaining = rest?;
+        let mut chars = remaining.char_indices();
+        while let Some((i, c)) = chars.next() {
+            if c == ESCAPE {
+                /* the next character is part of this element */
+                chars.next();
+            } else if remaining[i..].starts_with(item_delim) {
+                rest = Some(&remaining[i + item_delim.len()..]);
+                return Some(&remaining[..i]);
+            }
+        }
+        rest = None;
+        Some(remaining)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_delimiters() {
+        let entry = Entry::<_, _, ()>::from_line("Key: value", identity);
+        assert_eq!(entry.unwrap(), Entry::new("Key", "value"));
+
+        let entry = Entry::<_, Vec<_>, _>::from_collection("Key: a,b,c", identity);
+        assert_eq!(entry.unwrap(), Entry::new("Key", vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn tab_delimited_line() {
+        let entry = Entry::<_, _, ()>::from_line_with("Key\tvalue: with colon", "\t", identity);
+        assert_eq!(entry.unwrap(), Entry::new("Key", "value: with colon"));
+
+        let entry = Entry::<&str, _, ()>::try_from_line_with("Key\t3", "\t", str::parse::<i32>);
+        assert_eq!(entry.unwrap().unwrap(), Entry::new("Key", 3));
+    }
+
+    #[test]
+    fn tab_delimited_collection() {
+        let entry =
+            Entry::<_, Vec<_>, _>::from_collection_with("Key\ta,b|c,d", "\t", "|", identity);
+        assert_eq!(entry.unwrap(), Entry::new("Key", vec!["a,b", "c,d"]));
+
+        let entry = Entry::<&str, Vec<_>, _>::try_from_collection_with(
+            "Key\t1\t2",
+            "\t",
+            "\t",
+            str::parse::<i32>,
+        );
+        assert_eq!(entry.unwrap().unwrap(), Entry::new("Key", vec![1, 2]));
+    }
+
+    #[test]
+    fn missing_delimiter() {
+        let entry = Entry::<&str, &str, ()>::from_line_with("Key: value", "\t", identity);
+        assert_eq!(entry, None);
+    }
+
+    #[test]
+    fn bom_is_stripped() {
+        assert_eq!(strip_bom("\u{feff}Key: value"), "Key: value");
+        assert_eq!(strip_bom("Key: value"), "Key: value");
+    }
+
+    #[test]
+    fn escaped_delimiters() {
+        let word = "A,B\nC\\";
+        assert_eq!(unescape(&escape(word)), word);
+
+        let line = format!("Key: {},D", escape(word));
+        assert!(!line.contains('\n'));
+        let entry = Entry::<_, Vec<_>, _>::from_collection(&line, unescape);
+        assert_eq!(
+            entry.unwrap(),
+            Entry::new("Key", vec![word.to_owned(), "D".to_owned()])
+        );
+    }
+
+    #[test]
+    fn empty_collection() {
+        let entry = Entry::<_, Vec<&str>, _>::from_collection("Key: ", identity);
+        assert_eq!(entry.unwrap(), Entry::new("Key", vec![]));
+
+        let entry = Entry::<&str, Vec<_>, _>::try_from_collection("Key: ", str::parse::<i32>);
+        assert_eq!(entry.unwrap().unwrap(), Entry::new("Key", vec![]));
+    }
+}
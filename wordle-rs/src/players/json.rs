@@ -0,0 +1,170 @@
+//! This module provides methods to save a player to and
+//! load a player from JSON, so other tools can read the
+//! player's data.
+//!
+//! ## Required features
+//!
+//! **`serde`**
+//!
+//! Author: Benjamin Hall
+
+use std::borrow::Borrow;
+
+use serde::{Deserialize, Serialize};
+
+use super::PlayerInfo;
+use crate::MAX_GUESSES;
+
+/// The data of a player, in the form it is written as JSON.
+#[derive(Debug, Serialize, Deserialize)]
+struct PlayerJson {
+    username: String,
+    /* sorted so the same player is always written the same way */
+    words_played: Vec<String>,
+    num_guesses: [usize; MAX_GUESSES],
+    max_win_streak: usize,
+    cur_win_streak: usize,
+    losses: usize,
+    hard_mode_violations: usize,
+    game_history: Vec<bool>,
+    track_streaks: bool,
+}
+
+impl<S> PlayerInfo<S>
+where
+    S: Borrow<str>,
+{
+    /// Writes this player's data as JSON.
+    ///
+    /// The words played are written in sorted order, so the
+    /// same player is always written the same way.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    ///
+    /// let json = player.to_json();
+    /// assert!(json.contains(r#""words_played":["TRACE"]"#));
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut words_played: Vec<_> = self.words_played.iter().cloned().collect();
+        words_played.sort_unstable();
+
+        let player_json = PlayerJson {
+            username: self.username.borrow().to_owned(),
+            words_played,
+            num_guesses: self.num_guesses,
+            max_win_streak: self.max_win_streak,
+            cur_win_streak: self.cur_win_streak,
+            losses: self.losses,
+            hard_mode_violations: self.hard_mode_violations,
+            game_history: self.game_history.clone(),
+            track_streaks: self.track_streaks,
+        };
+        /* there are no maps, so every value can be written as JSON */
+        serde_json::to_string(&player_json).unwrap()
+    }
+}
+
+impl PlayerInfo<String> {
+    /// Reads a player's data from JSON written by [`PlayerInfo::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data is not
+    /// valid JSON, if any of the player's data is missing or
+    /// has the wrong type, or if the player's statistics are
+    /// not consistent, the same as for a player file.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let player = PlayerInfo::new(String::from("user"));
+    /// let restored = PlayerInfo::from_json(&player.to_json()).unwrap();
+    /// assert_eq!(restored, player);
+    ///
+    /// assert!(PlayerInfo::from_json("{").is_err());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let player_json: PlayerJson = serde_json::from_str(json)?;
+
+        let player = Self {
+            username: player_json.username,
+            words_played: player_json.words_played.into_iter().collect(),
+            num_guesses: player_json.num_guesses,
+            max_win_streak: player_json.max_win_streak,
+            cur_win_streak: player_json.cur_win_streak,
+            losses: player_json.losses,
+            hard_mode_violations: player_json.hard_mode_violations,
+            game_history: player_json.game_history,
+            track_streaks: player_json.track_streaks,
+        };
+        if !player.is_consistent(player.games_played()) {
+            return Err(serde::de::Error::custom(
+                "corrupt player data: inconsistent statistics",
+            ));
+        }
+
+        Ok(player)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_won_word(String::from("CRATE,S"), 5);
+        player.add_hard_mode_violation();
+        player.set_track_streaks(false);
+
+        let json = player.to_json();
+        let restored = PlayerInfo::from_json(&json).unwrap();
+        assert_eq!(restored, player);
+        assert_eq!(restored.stats().distribution, [0, 0, 1, 0, 1, 0]);
+        /* the same player is always written the same way */
+        assert_eq!(restored.to_json(), json);
+    }
+
+    #[test]
+    fn malformed_json_is_an_error() {
+        let err = PlayerInfo::from_json("{\"username\": \"player\"").unwrap_err();
+        assert!(err.is_eof());
+
+        let err = PlayerInfo::from_json("{\"username\": \"player\"}").unwrap_err();
+        assert!(err.is_data());
+        assert!(err.to_string().contains("missing field"));
+
+        let json = PlayerInfo::new(String::from("player"))
+            .to_json()
+            .replace("[0,0,0,0,0,0]", "[0,0,0]");
+        assert!(PlayerInfo::from_json(&json).unwrap_err().is_data());
+    }
+
+    #[test]
+    fn inconsistent_json_is_an_error() {
+        let json = PlayerInfo::new(String::from("player"))
+            .to_json()
+            .replace(r#""cur_win_streak":0"#, r#""cur_win_streak":9"#);
+        let err = PlayerInfo::from_json(&json).unwrap_err();
+        assert!(err.is_data());
+        assert!(err.to_string().contains("inconsistent statistics"));
+
+        /* every word played must have been won or lost */
+        let json = PlayerInfo::new(String::from("player"))
+            .to_json()
+            .replace(r#""words_played":[]"#, r#""words_played":["TRACE"]"#);
+        assert!(PlayerInfo::from_json(&json).unwrap_err().is_data());
+    }
+}
@@ -10,13 +10,31 @@
 
 use std::{
     borrow::Borrow,
-    collections::HashSet,
+    collections::{BTreeMap, BTreeSet, HashSet},
+    convert::identity,
     fmt::{self, Write},
     fs::File,
     io::{self, BufReader, BufWriter, Read},
+    path::Path,
+    thread,
 };
 
-mod database;
+use crate::{WordleError, MAX_GUESSES, WORD_LEN};
+
+use self::selector::WordSelector;
+
+/// Version of the player database format written by
+/// [`PlayerInfo::write_to_file`].
+///
+/// Files without a version header are version 0, which is
+/// upgraded to the current version the next time it is written.
+pub const DATABASE_VERSION: usize = 1;
+
+pub(crate) mod database;
+pub use self::database::strip_bom;
+#[cfg(feature = "serde")]
+mod json;
+pub mod selector;
 
 /// Contains information about a Wordle player.
 ///
@@ -26,6 +44,10 @@ mod database;
 /// - guess distribution
 /// - maximum win streak
 /// - current win streak
+/// - number of games lost
+/// - number of hard mode violations
+/// - history of games won and lost
+/// - preference for whether win streaks are tracked
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlayerInfo<S>
 where
@@ -33,9 +55,86 @@ where
 {
     username: S,
     words_played: HashSet<String>,
-    num_guesses: [usize; 6],
+    num_guesses: [usize; MAX_GUESSES],
     max_win_streak: usize,
     cur_win_streak: usize,
+    losses: usize,
+    hard_mode_violations: usize,
+    /* whether each game was won, from oldest to newest */
+    game_history: Vec<bool>,
+    track_streaks: bool,
+}
+
+/// Number of recent games used for the recent win rate in
+/// [`PlayerInfo::get_stats`].
+pub const RECENT_GAMES: usize = 10;
+
+/// Options for formatting a player's statistics.
+///
+/// See [`PlayerInfo::get_stats_with`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StatsFormat {
+    /// Number of decimal places to show in win rates
+    pub win_rate_decimals: usize,
+}
+
+/// Contains the statistics of a Wordle player.
+///
+/// These are computed by [`PlayerInfo::stats`], and are
+/// what [`PlayerInfo::get_stats`] formats for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of games played
+    pub games_played: usize,
+    /// Number of games won
+    pub games_won: usize,
+    /// Number of games lost
+    pub games_lost: usize,
+    /// Percentage of games won, rounded to the nearest whole number
+    pub win_rate: u32,
+    /// Current win streak
+    pub current_streak: usize,
+    /// Maximum win streak
+    pub max_streak: usize,
+    /// Number of games won in each number of guesses,
+    /// where `distribution[n - 1]` is the number of
+    /// games won in n guesses
+    pub distribution: [usize; MAX_GUESSES],
+    /// Number of guesses that broke the rules of hard mode
+    pub hard_mode_violations: usize,
+}
+
+/// Achievements a Wordle player can earn.
+///
+/// Achievements are not saved, since they are all found
+/// from the player's statistics by [`PlayerInfo::achievements`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Achievement {
+    /// Won a game
+    FirstWin,
+    /// Won 10 games
+    TenWins,
+    /// Reached a win streak of 5 games
+    Streak5,
+    /// Reached a win streak of 10 games
+    Streak10,
+    /// Won a game in 1 guess
+    GeniusSolve,
+    /// Won a game on the last guess
+    CloseCall,
+}
+
+impl fmt::Display for Achievement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::FirstWin => write!(f, "First Win"),
+            Self::TenWins => write!(f, "Ten Wins"),
+            Self::Streak5 => write!(f, "5 Game Win Streak"),
+            Self::Streak10 => write!(f, "10 Game Win Streak"),
+            Self::GeniusSolve => write!(f, "Genius Solve"),
+            Self::CloseCall => write!(f, "Close Call"),
+        }
+    }
 }
 
 impl<S> fmt::Display for PlayerInfo<S>
@@ -43,23 +142,32 @@ where
     S: Borrow<str>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "Username: {}", self.username.borrow())?;
-        writeln!(
-            f,
-            "Words Played: {}",
-            self.words_played
-                .iter()
-                .cloned()
-                .collect::<Vec<_>>()
-                .join(",")
-        )?;
+        writeln!(f, "Version: {DATABASE_VERSION}")?;
+        writeln!(f, "Username: {}", database::escape(self.username.borrow()))?;
+        /* sort the words so the same player is always written the same way */
+        let mut words_played: Vec<_> = self
+            .words_played
+            .iter()
+            .map(|word| database::escape(word))
+            .collect();
+        words_played.sort_unstable();
+        writeln!(f, "Words Played: {}", words_played.join(","))?;
         writeln!(
             f,
             "Number of Guesses: {}",
             self.num_guesses.map(|i| i.to_string()).join(",")
         )?;
         writeln!(f, "Maximum Win Streak: {}", self.max_win_streak)?;
-        writeln!(f, "Current Win Streak: {}", self.cur_win_streak)
+        writeln!(f, "Current Win Streak: {}", self.cur_win_streak)?;
+        writeln!(f, "Losses: {}", self.losses)?;
+        writeln!(f, "Hard Mode Violations: {}", self.hard_mode_violations)?;
+        let game_history: Vec<_> = self
+            .game_history
+            .iter()
+            .map(|&won| if won { "W" } else { "L" })
+            .collect();
+        writeln!(f, "Game History: {}", game_history.join(","))?;
+        writeln!(f, "Track Streaks: {}", self.track_streaks)
     }
 }
 
@@ -79,11 +187,16 @@ where
     #[inline]
     #[must_use]
     pub fn new(username: S) -> Self {
-        Self::load(username, HashSet::new(), [0; 6], 0, 0)
+        Self::load(username, HashSet::new(), [0; MAX_GUESSES], 0, 0)
     }
 
     /// Loads data for an existing player.
     ///
+    /// The player starts with no losses, no hard mode violations,
+    /// an empty history of games, and win streaks tracked. Use
+    /// [`PlayerInfo::try_load`] to count every word played that
+    /// is not in the guess distribution as a loss.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -112,7 +225,7 @@ where
     pub const fn load(
         username: S,
         words_played: HashSet<String>,
-        num_guesses: [usize; 6],
+        num_guesses: [usize; MAX_GUESSES],
         max_win_streak: usize,
         cur_win_streak: usize,
     ) -> Self {
@@ -122,6 +235,89 @@ where
             num_guesses,
             max_win_streak,
             cur_win_streak,
+            losses: 0,
+            hard_mode_violations: 0,
+            game_history: Vec::new(),
+            track_streaks: true,
+        }
+    }
+
+    /// Loads data for an existing player, checking that
+    /// the data is consistent.
+    ///
+    /// Unlike [`PlayerInfo::load`], this function checks that:
+    /// - the current win streak is not longer than the maximum win streak
+    /// - the number of games won is not more than the number of words played
+    /// - the maximum win streak is not longer than the number of games won
+    ///
+    /// No number of losses is given, so every game must be one of the
+    /// words played, and every word played that is not in the guess
+    /// distribution is counted as a loss. Player files are checked
+    /// the same way, with the losses counted as games once they are stored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error describing the first
+    /// of these checks that the data fails.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::{players::PlayerInfo, WordleError};
+    /// let words_played = HashSet::from([String::from("TRACE")]);
+    /// let num_guesses = [0, 0, 0, 1, 0, 0];
+    ///
+    /// let player = PlayerInfo::try_load("user", words_played.clone(), num_guesses, 1, 1);
+    /// assert!(player.is_ok());
+    ///
+    /// let player = PlayerInfo::try_load("user", words_played, num_guesses, 1, 2);
+    /// assert_eq!(
+    ///     player.unwrap_err(),
+    ///     WordleError::StreakExceedsMax { current: 2, max: 1 }
+    /// );
+    /// ```
+    pub fn try_load(
+        username: S,
+        words_played: HashSet<String>,
+        num_guesses: [usize; MAX_GUESSES],
+        max_win_streak: usize,
+        cur_win_streak: usize,
+    ) -> Result<Self, WordleError> {
+        let wins = num_guesses.iter().sum();
+        check_stats(wins, words_played.len(), max_win_streak, cur_win_streak)?;
+
+        Ok(Self::load_counting_losses(
+            username,
+            words_played,
+            num_guesses,
+            max_win_streak,
+            cur_win_streak,
+        ))
+    }
+
+    /// Loads data for an existing player like [`PlayerInfo::load`],
+    /// but counts every word played that is not in the guess
+    /// distribution as a loss.
+    fn load_counting_losses(
+        username: S,
+        words_played: HashSet<String>,
+        num_guesses: [usize; MAX_GUESSES],
+        max_win_streak: usize,
+        cur_win_streak: usize,
+    ) -> Self {
+        let wins: usize = num_guesses.iter().sum();
+        let losses = words_played.len().saturating_sub(wins);
+        Self {
+            losses,
+            ..Self::load(
+                username,
+                words_played,
+                num_guesses,
+                max_win_streak,
+                cur_win_streak,
+            )
         }
     }
 
@@ -142,10 +338,154 @@ where
         self.username.borrow()
     }
 
+    /// Gets the current win streak of this player.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.get_cur_win_streak(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn get_cur_win_streak(&self) -> usize {
+        self.cur_win_streak
+    }
+
+    /// Gets the maximum win streak of this player.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_lost_word(String::from("BEBOP"));
+    /// assert_eq!(player.get_max_win_streak(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn get_max_win_streak(&self) -> usize {
+        self.max_win_streak
+    }
+
+    /// Gets the number of times this player has made a guess
+    /// that broke the rules of hard mode.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_hard_mode_violation();
+    /// assert_eq!(player.get_hard_mode_violations(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn get_hard_mode_violations(&self) -> usize {
+        self.hard_mode_violations
+    }
+
+    /// Checks whether this player's win streaks are tracked.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let player = PlayerInfo::new("user");
+    /// assert!(player.tracks_streaks());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn tracks_streaks(&self) -> bool {
+        self.track_streaks
+    }
+
+    /// Sets whether this player's win streaks are tracked.
+    ///
+    /// While streaks are not tracked, the current win streak stays
+    /// at 0, and neither win streak is updated or shown in the
+    /// player's statistics. Turning off tracking resets the current
+    /// win streak to 0, but the maximum win streak is kept, so it
+    /// is not lost if tracking is turned back on.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.set_track_streaks(false);
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.get_cur_win_streak(), 0);
+    /// ```
+    #[inline]
+    pub fn set_track_streaks(&mut self, track_streaks: bool) {
+        self.track_streaks = track_streaks;
+        if !track_streaks {
+            self.cur_win_streak = 0;
+        }
+    }
+
+    /// Resets all of this player's statistics, as if they
+    /// had never played a game.
+    ///
+    /// The username and whether win streaks are tracked
+    /// are kept.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    ///
+    /// player.reset_stats();
+    /// assert_eq!(player.games_played(), 0);
+    /// assert_eq!(player.get_cur_win_streak(), 0);
+    /// ```
+    pub fn reset_stats(&mut self) {
+        self.words_played.clear();
+        self.num_guesses = [0; MAX_GUESSES];
+        self.max_win_streak = 0;
+        self.cur_win_streak = 0;
+        self.losses = 0;
+        self.hard_mode_violations = 0;
+        self.game_history.clear();
+    }
+
+    /// Records that this player made a guess that broke
+    /// the rules of hard mode.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_hard_mode_violation();
+    /// ```
+    #[inline]
+    pub fn add_hard_mode_violation(&mut self) {
+        self.hard_mode_violations += 1;
+    }
+
     /// Gets a random word this player has not yet played.
     ///
+    /// The word is always from `dictionary`, so it should be the
+    /// list of answers rather than every word allowed as a guess.
     /// If there are no unplayed words, this function returns [`None`].
     ///
+    /// The word is chosen using fastrand's global generator, so
+    /// seeding it with [`fastrand::seed`] makes the choice reproducible.
+    ///
     /// # Examples:
     ///
     /// Basic usage:
@@ -168,151 +508,987 @@ where
         &self,
         dictionary: &'a HashSet<String, impl std::hash::BuildHasher>,
     ) -> Option<&'a str> {
-        let unplayed_words_cnt = dictionary.len() - self.words_played.len();
-        let random_word_idx = fastrand::usize(0..unplayed_words_cnt);
+        /* seed from the global generator so that seeding it is enough */
+        let rng = fastrand::Rng::with_seed(fastrand::u64(..));
+        self.get_random_word_with(dictionary, &rng)
+    }
+
+    /// Iterates over the words this player has not yet played,
+    /// in sorted order.
+    ///
+    /// Unlike [`PlayerInfo::get_random_word`], the order is always
+    /// the same, so the words can be played alphabetically.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::BTreeSet;
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("CRATE"), 3);
+    ///
+    /// let dictionary = BTreeSet::from([String::from("TRACE"), String::from("CRATE")]);
+    /// assert!(player.unplayed_iter(&dictionary).eq(["TRACE"]));
+    /// ```
+    pub fn unplayed_iter<'a>(
+        &'a self,
+        dictionary: &'a BTreeSet<String>,
+    ) -> impl Iterator<Item = &'a str> + 'a {
         dictionary
             .iter()
             .filter(|w| !self.words_played.contains(*w))
-            .nth(random_word_idx)
             .map(String::as_str)
     }
 
-    /// Adds a word the player has successfully guessed to their database.
+    /// Calculates how much of a dictionary this player has completed.
     ///
-    /// This function increments the current win streak, adds
-    /// the guess to the list of words played and to the guess
-    /// distribution, and updates the max win streak if appropriate.
+    /// This is the fraction of words in the dictionary that the player has
+    /// played. Words the player has played that are no longer in the
+    /// dictionary are not counted. If the dictionary is empty, this
+    /// function returns 0.
     ///
-    /// # Examples:
+    /// # Examples
     ///
     /// Basic usage:
     /// ```
+    /// # use std::collections::HashSet;
     /// # use wordle::players::PlayerInfo;
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    ///
     /// let mut player = PlayerInfo::new("user");
-    /// // player got TRACE in 3 guesses
     /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.completion_ratio(&dictionary), 0.5);
     /// ```
-    #[inline]
-    pub fn add_won_word(&mut self, word: String, num_guesses: usize) {
-        self.words_played.insert(word);
-        self.num_guesses[num_guesses - 1] += 1;
-        self.cur_win_streak += 1;
-        self.max_win_streak = std::cmp::max(self.max_win_streak, self.cur_win_streak);
+    #[must_use]
+    pub fn completion_ratio(
+        &self,
+        dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    ) -> f64 {
+        if dictionary.is_empty() {
+            return 0.0;
+        }
+
+        let words_completed = self
+            .words_played
+            .iter()
+            .filter(|w| dictionary.contains(*w))
+            .count();
+        words_completed as f64 / dictionary.len() as f64
     }
 
-    /// Adds a word the player has failed to guess to their database.
+    /// Counts the words in a dictionary this player has not yet played.
     ///
-    /// This function resets the current win streak to 0 and adds
-    /// the guess to the list of words played. The number of guesses
-    /// is not added to the player's guess distribution.
+    /// Words the player has played that are not in the dictionary
+    /// do not affect the count.
     ///
-    /// # Examples:
+    /// # Examples
     ///
     /// Basic usage:
     /// ```
+    /// # use std::collections::HashSet;
     /// # use wordle::players::PlayerInfo;
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    ///
     /// let mut player = PlayerInfo::new("user");
-    /// // player could not guess BEBOP within 6 guesses
-    /// player.add_lost_word(String::from("BEBOP"));
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.words_until_exhausted(&dictionary), 1);
     /// ```
-    #[inline]
-    pub fn add_lost_word(&mut self, word: String) {
-        self.words_played.insert(word);
-        self.cur_win_streak = 0;
+    #[must_use]
+    pub fn words_until_exhausted(
+        &self,
+        dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    ) -> usize {
+        dictionary
+            .iter()
+            .filter(|w| !self.words_played.contains(*w))
+            .count()
     }
 
-    /// Returns a string with formated player statistics.
+    /// Checks whether this player could reach a win streak of
+    /// `target` before running out of words in a dictionary.
     ///
-    /// Player statistics consist of:
-    /// - Number of words played
-    /// - Win rate
-    /// - Current and max win streak
-    /// - Guess distribution
+    /// The player would need to win every game, with one game
+    /// for each word in the dictionary they have not yet played.
     ///
     /// # Examples
     ///
     /// Basic usage:
     /// ```
+    /// # use std::collections::HashSet;
     /// # use wordle::players::PlayerInfo;
-    /// let player = PlayerInfo::new("user");
-    /// println!("{}", player.get_stats());
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    ///
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert!(player.can_reach_streak(&dictionary, 2));
+    /// assert!(!player.can_reach_streak(&dictionary, 3));
     /// ```
     #[must_use]
-    pub fn get_stats(&self) -> String {
-        let mut stats = String::new();
-        writeln!(stats, "Number of Words Played: {}", self.words_played.len()).unwrap();
-
-        let win_rate = if self.words_played.is_empty() {
-            0
-        } else {
-            (100.0 * self.num_guesses.iter().sum::<usize>() as f64 / self.words_played.len() as f64)
-                .round() as u32
-        };
-        writeln!(stats, "Win Rate: {win_rate}%").unwrap();
-
-        writeln!(stats, "Current Win Streak: {}", self.cur_win_streak).unwrap();
-        writeln!(stats, "Maximum Win Streak: {}", self.max_win_streak).unwrap();
-
-        writeln!(stats, "Guess Distribution:").unwrap();
-        let max_num_guesses = self.num_guesses.iter().max();
-
-        /* print the guess distribution with bars, max 12 */
-        let bar_factor = match max_num_guesses {
-            Some(max_num_guesses) => 12.0 / *max_num_guesses as f64,
-            None => 0.0,
-        };
-
-        for (i, num_guess) in self.num_guesses.iter().enumerate() {
-            let num_bars = (bar_factor * *num_guess as f64).round() as usize;
-            let bars = "=".repeat(num_bars);
-            writeln!(stats, "{}: {bars} {num_guess}", i + 1).unwrap();
-        }
-
-        stats.trim().to_owned()
+    pub fn can_reach_streak(
+        &self,
+        dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+        target: usize,
+    ) -> bool {
+        self.max_possible_streak(dictionary) >= target
     }
 
-    /// Writes this player's data to a file.
-    ///
-    /// # Errors
+    /// Scales this player's guess distribution into bars.
     ///
-    /// This function will return an error if creating the
-    /// file or writing to the file fails.
+    /// Each entry holds the number of games won in that many
+    /// guesses, along with the length of its bar. The bars are
+    /// scaled so the longest bar is `max_width` long, and each
+    /// length is rounded to the nearest whole number. If the player
+    /// has not won any games, every bar has a length of 0.
     ///
     /// # Examples
     ///
     /// Basic usage:
-    /// ```no_run
-    /// # use std::io;
+    /// ```
     /// # use wordle::players::PlayerInfo;
-    /// # fn main() -> io::Result<()> {
-    /// let player = PlayerInfo::new("user");
-    /// player.write_to_file("user.txt")?;
-    /// # Ok(())
-    /// # }
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_distribution(&[0, 1, 2, 0, 0, 0], false);
+    /// assert_eq!(
+    ///     player.distribution_bars(10),
+    ///     [(0, 0), (1, 5), (2, 10), (0, 0), (0, 0), (0, 0)]
+    /// );
     /// ```
-    pub fn write_to_file(&self, filename: &str) -> io::Result<()> {
-        use std::io::Write;
-        let file = File::create(filename)?;
-        let mut writer = BufWriter::new(file);
-        writer.write_all(self.to_string().as_bytes())
+    #[must_use]
+    pub fn distribution_bars(&self, max_width: usize) -> [(usize, usize); MAX_GUESSES] {
+        let max_num_guesses = self.num_guesses.iter().copied().max().unwrap_or(0);
+        self.num_guesses.map(|num_guess| {
+            let num_bars = if max_num_guesses == 0 {
+                0
+            } else {
+                (max_width as f64 * num_guess as f64 / max_num_guesses as f64).round() as usize
+            };
+            (num_guess, num_bars)
+        })
     }
-}
 
-impl PlayerInfo<String> {
-    /// Reads a player's info from a file.
+    /// Gets the achievements this player has earned.
     ///
-    /// All errors with reading the file, including any errors
-    /// with parsing data, is propagated up to the caller.
-    ///
-    /// # Errors
-    ///
-    /// This function will return an error if opening the file
-    /// or reading from the file fails.
+    /// The achievements are returned in the order they are
+    /// declared in [`Achievement`].
     ///
     /// # Examples
     ///
     /// Basic usage:
-    /// ```no_run
+    /// ```
+    /// # use wordle::players::{Achievement, PlayerInfo};
+    /// let mut player = PlayerInfo::new("user");
+    /// assert!(player.achievements().is_empty());
+    ///
+    /// player.add_won_word(String::from("TRACE"), 1);
+    /// assert_eq!(
+    ///     player.achievements(),
+    ///     [Achievement::FirstWin, Achievement::GeniusSolve]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn achievements(&self) -> Vec<Achievement> {
+        let Stats {
+            games_won,
+            max_streak,
+            distribution,
+            ..
+        } = self.stats();
+
+        [
+            (Achievement::FirstWin, games_won >= 1),
+            (Achievement::TenWins, games_won >= 10),
+            (Achievement::Streak5, max_streak >= 5),
+            (Achievement::Streak10, max_streak >= 10),
+            (Achievement::GeniusSolve, distribution[0] > 0),
+            (Achievement::CloseCall, distribution[MAX_GUESSES - 1] > 0),
+        ]
+        .into_iter()
+        .filter_map(|(achievement, earned)| earned.then_some(achievement))
+        .collect()
+    }
+
+    /// Gets the longest win streak this player could reach
+    /// before running out of words in a dictionary.
+    ///
+    /// This assumes the player wins every game, with one game
+    /// for each word in the dictionary they have not yet played.
+    /// Words played that are no longer in the dictionary do not
+    /// count towards the remaining games.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::players::PlayerInfo;
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    ///
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.max_possible_streak(&dictionary), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn max_possible_streak(
+        &self,
+        dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    ) -> usize {
+        self.cur_win_streak + self.words_until_exhausted(dictionary)
+    }
+
+    /// Gets a random word this player has not yet played
+    /// using the given random number generator.
+    ///
+    /// The unplayed words are sorted before one is chosen, so the
+    /// same generator state, dictionary, and words played always
+    /// result in the same word. If there are no unplayed words,
+    /// this function returns [`None`].
+    ///
+    /// # Examples:
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::players::PlayerInfo;
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    /// let player = PlayerInfo::new("user");
+    ///
+    /// let word = player.get_random_word_with(&dictionary, &fastrand::Rng::with_seed(7));
+    /// let same_word = player.get_random_word_with(&dictionary, &fastrand::Rng::with_seed(7));
+    /// assert_eq!(word, same_word);
+    /// ```
+    #[must_use]
+    pub fn get_random_word_with<'a>(
+        &self,
+        dictionary: &'a HashSet<String, impl std::hash::BuildHasher>,
+        rng: &fastrand::Rng,
+    ) -> Option<&'a str> {
+        let mut unplayed_words: Vec<_> = dictionary
+            .iter()
+            .filter(|w| !self.words_played.contains(*w))
+            .map(String::as_str)
+            .collect();
+        if unplayed_words.is_empty() {
+            return None;
+        }
+
+        unplayed_words.sort_unstable();
+        Some(unplayed_words[rng.usize(0..unplayed_words.len())])
+    }
+
+    /// Gets a word this player has not yet played using the
+    /// given word selector.
+    ///
+    /// The unplayed words are sorted before they are given to the
+    /// selector. If there are no unplayed words, or the selector
+    /// does not choose a word, this function returns [`None`].
+    ///
+    /// # Examples:
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::players::{selector::AlphabeticalSelector, PlayerInfo};
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    /// let player = PlayerInfo::new("user");
+    ///
+    /// let word = player.get_word_with(&dictionary, &mut AlphabeticalSelector);
+    /// assert_eq!(word, Some("CRATE"));
+    /// ```
+    #[must_use]
+    pub fn get_word_with<'a, W: WordSelector>(
+        &self,
+        dictionary: &'a HashSet<String, impl std::hash::BuildHasher>,
+        selector: &mut W,
+    ) -> Option<&'a str> {
+        let mut unplayed_words: Vec<_> = dictionary
+            .iter()
+            .filter(|w| !self.words_played.contains(*w))
+            .map(String::as_str)
+            .collect();
+        unplayed_words.sort_unstable();
+        selector.select(&unplayed_words)
+    }
+
+    /// Gets up to `n` distinct random words this player has not
+    /// yet played using the given random number generator.
+    ///
+    /// If there are fewer than `n` unplayed words, every unplayed
+    /// word is returned in a random order.
+    ///
+    /// # Examples:
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::players::PlayerInfo;
+    /// let dictionary = HashSet::from([
+    ///     String::from("TRACE"),
+    ///     String::from("CRATE"),
+    ///     String::from("BLIND"),
+    /// ]);
+    /// let player = PlayerInfo::new("user");
+    ///
+    /// let words = player.get_random_words(&dictionary, 2, &fastrand::Rng::new());
+    /// assert_eq!(words.len(), 2);
+    /// assert_ne!(words[0], words[1]);
+    /// ```
+    #[must_use]
+    pub fn get_random_words<'a>(
+        &self,
+        dictionary: &'a HashSet<String, impl std::hash::BuildHasher>,
+        n: usize,
+        rng: &fastrand::Rng,
+    ) -> Vec<&'a str> {
+        let mut unplayed_words: Vec<_> = dictionary
+            .iter()
+            .filter(|w| !self.words_played.contains(*w))
+            .map(String::as_str)
+            .collect();
+
+        unplayed_words.sort_unstable();
+        rng.shuffle(&mut unplayed_words);
+        unplayed_words.truncate(n);
+        unplayed_words
+    }
+
+    /// Gets a random puzzle this player has not yet played.
+    ///
+    /// A puzzle is identified by a number that can be shared with
+    /// other players, who can then use [`get_puzzle_word`] to play
+    /// the same word. This function returns the puzzle number along
+    /// with its word. If there are no unplayed words, this function
+    /// returns [`None`].
+    ///
+    /// The word is chosen using fastrand's global generator, so
+    /// seeding it with [`fastrand::seed`] makes the choice reproducible.
+    ///
+    /// # Examples:
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use wordle::players::{self, PlayerInfo};
+    /// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+    /// let player = PlayerInfo::new("user");
+    ///
+    /// let (puzzle_id, word) = player.get_random_puzzle(&dictionary).unwrap();
+    /// assert_eq!(players::get_puzzle_word(&dictionary, puzzle_id), Some(word));
+    /// ```
+    #[must_use]
+    pub fn get_random_puzzle<'a>(
+        &self,
+        dictionary: &'a HashSet<String, impl std::hash::BuildHasher>,
+    ) -> Option<(u32, &'a str)> {
+        let words = sorted_words(dictionary);
+        let num_words = u32::try_from(words.len()).ok()?;
+        let unplayed_indices: Vec<_> = (0..num_words)
+            .filter(|&i| !self.words_played.contains(words[i as usize]))
+            .collect();
+        if unplayed_indices.is_empty() {
+            return None;
+        }
+
+        let index = unplayed_indices[fastrand::usize(..unplayed_indices.len())];
+        /* add a random multiple of the number of words so the number does not give away the word */
+        let puzzle_id = fastrand::u32(..=(u32::MAX - index) / num_words) * num_words + index;
+        Some((puzzle_id, words[index as usize]))
+    }
+
+    /// Adds a word the player has successfully guessed to their database.
+    ///
+    /// This function increments the current win streak if win
+    /// streaks are tracked (see [`PlayerInfo::set_track_streaks`]), adds
+    /// the guess to the list of words played and to the guess
+    /// distribution, and updates the max win streak if appropriate.
+    ///
+    /// If the player has already played the word, the game is
+    /// treated as a replay. A replay is not counted at all, so it
+    /// does not change the guess distribution, the win streaks, or
+    /// the history of games, and each word is counted at most once.
+    /// This function returns whether the word was newly added to
+    /// the list of words played.
+    ///
+    /// # Examples:
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// // player got TRACE in 3 guesses
+    /// assert!(player.add_won_word(String::from("TRACE"), 3));
+    /// // player replayed TRACE and got it in 2 guesses
+    /// assert!(!player.add_won_word(String::from("TRACE"), 2));
+    /// ```
+    #[inline]
+    pub fn add_won_word(&mut self, word: String, num_guesses: usize) -> bool {
+        if !self.words_played.insert(word) {
+            /* replays are not counted */
+            return false;
+        }
+        self.num_guesses[num_guesses - 1] += 1;
+        if self.track_streaks {
+            self.cur_win_streak += 1;
+            self.max_win_streak = std::cmp::max(self.max_win_streak, self.cur_win_streak);
+        }
+        self.game_history.push(true);
+        true
+    }
+
+    /// Adds a word the player has failed to guess to their database.
+    ///
+    /// This function resets the current win streak to 0, adds
+    /// the guess to the list of words played, and counts the loss.
+    /// The number of guesses is not added to the player's guess
+    /// distribution. Like a won replay, a lost replay is not counted,
+    /// so it does not reset the win streak. This function returns
+    /// whether the word was newly added to the list of words played.
+    ///
+    /// # Examples:
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// // player could not guess BEBOP within 6 guesses
+    /// assert!(player.add_lost_word(String::from("BEBOP")));
+    /// ```
+    #[inline]
+    pub fn add_lost_word(&mut self, word: String) -> bool {
+        if !self.words_played.insert(word) {
+            /* replays are not counted */
+            return false;
+        }
+        self.losses += 1;
+        /* the streak is always 0 if it is not tracked */
+        self.cur_win_streak = 0;
+        self.game_history.push(false);
+        true
+    }
+
+    /// Checks whether this player has played a word.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    ///
+    /// assert!(player.has_played("TRACE"));
+    /// assert!(!player.has_played("CRATE"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_played(&self, word: &str) -> bool {
+        self.words_played.contains(word)
+    }
+
+    /// Removes a word from the list of words this player has played.
+    ///
+    /// Returns whether the word had been played. The number of
+    /// guesses for each word is not stored, so this function does
+    /// not change the guess distribution, the number of losses,
+    /// the win streaks, or the history of games. The word can
+    /// be chosen again by [`PlayerInfo::get_random_word`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    ///
+    /// assert!(player.remove_played_word("TRACE"));
+    /// assert!(!player.remove_played_word("TRACE"));
+    /// assert_eq!(player.games_won(), 1);
+    /// ```
+    #[inline]
+    pub fn remove_played_word(&mut self, word: &str) -> bool {
+        self.words_played.remove(word)
+    }
+
+    /// Adds won games from another source to the guess distribution.
+    ///
+    /// Each bucket of the guess distribution is increased by the
+    /// matching count in `dist`, where `dist[n - 1]` is the number
+    /// of games won in n guesses. No words are added to the list
+    /// of words played.
+    ///
+    /// If `wins_add_to_streak` is true, every imported win is treated
+    /// as following on from the current win streak, so the current
+    /// win streak grows by the total number of wins, and the max win
+    /// streak is updated if appropriate. Otherwise, both win streaks
+    /// are left unchanged, as they are if win streaks are not tracked.
+    /// Imported games never reset the streak.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_distribution(&[0, 1, 2, 0, 0, 0], true);
+    /// assert_eq!(player.games_won(), 3);
+    /// assert_eq!(player.get_cur_win_streak(), 3);
+    /// ```
+    pub fn add_distribution(&mut self, dist: &[usize; MAX_GUESSES], wins_add_to_streak: bool) {
+        for (num_guess, added) in self.num_guesses.iter_mut().zip(dist) {
+            *num_guess += added;
+        }
+        if wins_add_to_streak && self.track_streaks {
+            self.cur_win_streak += dist.iter().sum::<usize>();
+            self.max_win_streak = std::cmp::max(self.max_win_streak, self.cur_win_streak);
+        }
+    }
+
+    /// Gets the number of games this player has played.
+    ///
+    /// This is the number of games won, including any imported
+    /// with [`PlayerInfo::add_distribution`], plus the number of
+    /// games lost.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_lost_word(String::from("BEBOP"));
+    /// assert_eq!(player.games_played(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn games_played(&self) -> usize {
+        self.games_won() + self.losses
+    }
+
+    /// Gets the number of games this player has won.
+    ///
+    /// This is the total number of games in the guess distribution.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_lost_word(String::from("BEBOP"));
+    /// assert_eq!(player.games_won(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn games_won(&self) -> usize {
+        self.num_guesses.iter().sum()
+    }
+
+    /// Gets the number of games this player has lost.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_lost_word(String::from("BEBOP"));
+    /// assert_eq!(player.games_lost(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn games_lost(&self) -> usize {
+        self.losses
+    }
+
+    /// Counts how often each letter appeared at each position
+    /// across all the words this player has played.
+    ///
+    /// `heatmap[pos][letter]` is the number of words played with
+    /// the letter at that position, where letter 0 is 'A'. Words
+    /// that are not [`WORD_LEN`] uppercase letters from A to Z
+    /// are skipped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    ///
+    /// let heatmap = player.position_letter_heatmap();
+    /// assert_eq!(heatmap[0][usize::from(b'T' - b'A')], 1);
+    /// ```
+    #[must_use]
+    pub fn position_letter_heatmap(&self) -> [[usize; 26]; WORD_LEN] {
+        let mut heatmap = [[0; 26]; WORD_LEN];
+        let valid_words = self
+            .words_played
+            .iter()
+            .filter(|w| w.len() == WORD_LEN && w.bytes().all(|c| c.is_ascii_uppercase()));
+        for word in valid_words {
+            for (counts, c) in heatmap.iter_mut().zip(word.bytes()) {
+                counts[usize::from(c - b'A')] += 1;
+            }
+        }
+        heatmap
+    }
+
+    /// Finds the most common first letters of the words this
+    /// player has played.
+    ///
+    /// Up to `n` letters are returned with the number of words
+    /// played that start with each one, from most to least common.
+    /// Letters that are equally common are in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_won_word(String::from("CRATE"), 4);
+    /// player.add_lost_word(String::from("TOAST"));
+    ///
+    /// assert_eq!(player.favorite_initials(1), [('T', 2)]);
+    /// ```
+    #[must_use]
+    pub fn favorite_initials(&self, n: usize) -> Vec<(char, usize)> {
+        let mut initial_counts = BTreeMap::new();
+        for initial in self.words_played.iter().filter_map(|w| w.chars().next()) {
+            *initial_counts.entry(initial).or_insert(0) += 1;
+        }
+
+        let mut initials: Vec<_> = initial_counts.into_iter().collect();
+        /* the sort is stable, so ties stay in alphabetical order */
+        initials.sort_by(|(_, a), (_, b)| b.cmp(a));
+        initials.truncate(n);
+        initials
+    }
+
+    /// Computes this player's statistics.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_lost_word(String::from("CRATE"));
+    ///
+    /// let stats = player.stats();
+    /// assert_eq!(stats.games_played, 2);
+    /// assert_eq!(stats.win_rate, 50);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> Stats {
+        let games_played = self.games_played();
+        let games_won = self.games_won();
+        let win_rate = if games_played == 0 {
+            0
+        } else {
+            (100.0 * games_won as f64 / games_played as f64).round() as u32
+        };
+
+        Stats {
+            games_played,
+            games_won,
+            games_lost: self.losses,
+            win_rate,
+            current_streak: self.cur_win_streak,
+            max_streak: self.max_win_streak,
+            distribution: self.num_guesses,
+            hard_mode_violations: self.hard_mode_violations,
+        }
+    }
+
+    /// Calculates the percentage of the last `n` games that the
+    /// player won.
+    ///
+    /// If the player has played fewer than `n` games, all of
+    /// their games are used. Games are only recorded as they are
+    /// played, so games imported with [`PlayerInfo::add_distribution`]
+    /// are not included. If there are no games to use, this
+    /// function returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// assert_eq!(player.recent_win_rate(10), None);
+    ///
+    /// player.add_lost_word(String::from("BEBOP"));
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.recent_win_rate(10), Some(50.0));
+    /// assert_eq!(player.recent_win_rate(1), Some(100.0));
+    /// ```
+    #[must_use]
+    pub fn recent_win_rate(&self, n: usize) -> Option<f64> {
+        let recent_games = &self.game_history[self.game_history.len().saturating_sub(n)..];
+        if recent_games.is_empty() {
+            return None;
+        }
+        let recent_wins = recent_games.iter().filter(|&&won| won).count();
+        Some(100.0 * recent_wins as f64 / recent_games.len() as f64)
+    }
+
+    /// Calculates the average number of guesses this player
+    /// took in the games they won.
+    ///
+    /// If the player has not won any games, this function
+    /// returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// assert_eq!(player.average_guesses(), None);
+    ///
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_won_word(String::from("CRATE"), 5);
+    /// assert_eq!(player.average_guesses(), Some(4.0));
+    /// ```
+    #[must_use]
+    pub fn average_guesses(&self) -> Option<f64> {
+        let games_won = self.games_won();
+        if games_won == 0 {
+            return None;
+        }
+        /* index i of the distribution is for games won in i + 1 guesses */
+        let total_guesses: usize = self
+            .num_guesses
+            .iter()
+            .enumerate()
+            .map(|(i, &num_guess)| (i + 1) * num_guess)
+            .sum();
+        Some(total_guesses as f64 / games_won as f64)
+    }
+
+    /// Returns a string with formated player statistics.
+    ///
+    /// Player statistics consist of:
+    /// - Number of words played
+    /// - Win rate, overall and over the last [`RECENT_GAMES`] games
+    /// - Current and max win streak
+    /// - Fewest guesses in a won game
+    /// - Average guesses in a won game
+    /// - Guess distribution
+    /// - Number of hard mode violations
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let player = PlayerInfo::new("user");
+    /// println!("{}", player.get_stats());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_stats(&self) -> String {
+        self.get_stats_with(&StatsFormat::default())
+    }
+
+    /// Returns a string with formated player statistics,
+    /// using the given format options.
+    ///
+    /// The statistics are the same as for [`PlayerInfo::get_stats`],
+    /// which uses the default format options.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::{PlayerInfo, StatsFormat};
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// player.add_won_word(String::from("CRATE"), 4);
+    /// player.add_lost_word(String::from("BEBOP"));
+    ///
+    /// let stats = player.get_stats_with(&StatsFormat { win_rate_decimals: 1 });
+    /// assert!(stats.contains("Win Rate: 66.7%"));
+    /// ```
+    #[must_use]
+    pub fn get_stats_with(&self, format: &StatsFormat) -> String {
+        let Stats {
+            games_played,
+            games_won,
+            games_lost,
+            win_rate,
+            current_streak,
+            max_streak,
+            distribution,
+            hard_mode_violations,
+            ..
+        } = self.stats();
+
+        let mut stats = String::new();
+        writeln!(stats, "Number of Games Played: {games_played}").unwrap();
+        writeln!(stats, "Losses: {games_lost}").unwrap();
+        let decimals = format.win_rate_decimals;
+        /* keep the rounding of the whole number win rate from the statistics */
+        let win_rate = if decimals == 0 || games_played == 0 {
+            f64::from(win_rate)
+        } else {
+            100.0 * games_won as f64 / games_played as f64
+        };
+        match self.recent_win_rate(RECENT_GAMES) {
+            Some(recent_win_rate) => writeln!(
+                stats,
+                "Win Rate: {win_rate:.decimals$}% (Last {}: {recent_win_rate:.decimals$}%)",
+                /* fewer recent games are shown until the player has played enough */
+                RECENT_GAMES.min(self.game_history.len())
+            )
+            .unwrap(),
+            None => writeln!(stats, "Win Rate: {win_rate:.decimals$}%").unwrap(),
+        }
+
+        if self.track_streaks {
+            writeln!(stats, "Current Win Streak: {current_streak}").unwrap();
+            writeln!(stats, "Maximum Win Streak: {max_streak}").unwrap();
+        }
+
+        /* the best game is the fewest guesses with at least one win */
+        match distribution.iter().position(|&num_guess| num_guess > 0) {
+            Some(0) => writeln!(stats, "Best: 1 guess").unwrap(),
+            Some(i) => writeln!(stats, "Best: {} guesses", i + 1).unwrap(),
+            None => writeln!(stats, "Best: no wins yet").unwrap(),
+        }
+        if let Some(average_guesses) = self.average_guesses() {
+            writeln!(stats, "Average Guesses: {average_guesses:.2}").unwrap();
+        }
+
+        writeln!(stats, "Guess Distribution:").unwrap();
+        /* print the guess distribution with bars, max 12, and the share of wins */
+        for (i, (num_guess, num_bars)) in self.distribution_bars(12).into_iter().enumerate() {
+            let bars = "=".repeat(num_bars);
+            if games_won == 0 {
+                writeln!(stats, "{}: {bars} {num_guess}", i + 1).unwrap();
+            } else {
+                let percent = (100.0 * num_guess as f64 / games_won as f64).round();
+                writeln!(stats, "{}: {bars} {num_guess} ({percent}%)", i + 1).unwrap();
+            }
+        }
+
+        writeln!(stats, "Hard Mode Violations: {hard_mode_violations}").unwrap();
+
+        stats.trim().to_owned()
+    }
+
+    /// Returns this player's statistics as a row of comma-separated values.
+    ///
+    /// The columns are the ones named by [`csv_header`]. The username
+    /// is quoted if it contains a comma, a quote, or a newline. This
+    /// is meant for exporting statistics, and is not the format of
+    /// the player database.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new("user");
+    /// player.add_won_word(String::from("TRACE"), 3);
+    /// assert_eq!(player.to_csv_row(), "user,1,1,0,1,1,0,0,1,0,0,0");
+    /// ```
+    #[must_use]
+    pub fn to_csv_row(&self) -> String {
+        let username = self.username.borrow();
+        let mut row = if username.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", username.replace('"', "\"\""))
+        } else {
+            username.to_owned()
+        };
+        let columns = [
+            self.words_played.len(),
+            self.games_won(),
+            self.losses,
+            self.max_win_streak,
+            self.cur_win_streak,
+        ];
+        for column in columns.into_iter().chain(self.num_guesses) {
+            write!(row, ",{column}").unwrap();
+        }
+        row
+    }
+
+    /// Writes this player's data to a file.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if creating the
+    /// file or writing to the file fails.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// # use std::io;
+    /// # use wordle::players::PlayerInfo;
+    /// # fn main() -> io::Result<()> {
+    /// let player = PlayerInfo::new("user");
+    /// player.write_to_file("user.txt")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to_file(&self, filename: &str) -> io::Result<()> {
+        use std::io::Write;
+        let file = File::create(filename)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(self.to_string().as_bytes())
+    }
+}
+
+impl PlayerInfo<String> {
+    /// Moves this player's data to a new username.
+    ///
+    /// All of the player's statistics are kept. The caller
+    /// is responsible for updating any database files.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::PlayerInfo;
+    /// let mut player = PlayerInfo::new(String::from("user"));
+    /// player.add_won_word(String::from("TRACE"), 3);
+    ///
+    /// let player = player.rename(String::from("new_user"));
+    /// assert_eq!(player.get_username(), "new_user");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rename(self, new_username: String) -> Self {
+        Self {
+            username: new_username,
+            ..self
+        }
+    }
+
+    /// Reads a player's info from a file.
+    ///
+    /// All errors with reading the file, including any errors
+    /// with parsing data, is propagated up to the caller.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if opening the file
+    /// or reading from the file fails.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
     /// # use std::io;
     /// # use wordle::players::PlayerInfo;
     /// # fn main() -> io::Result<()> {
@@ -320,11 +1496,15 @@ impl PlayerInfo<String> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_file(filename: &str) -> io::Result<Option<Self>> {
+    pub fn from_file(filename: impl AsRef<Path>) -> io::Result<Option<Self>> {
+        let filename = filename.as_ref();
         let bad_data_err = || {
             io::Error::new(
                 io::ErrorKind::InvalidData,
-                format!("Error: corrupt player database file: {}", filename),
+                format!(
+                    "Error: corrupt player database file: {}",
+                    filename.display()
+                ),
             )
         };
 
@@ -343,28 +1523,96 @@ impl PlayerInfo<String> {
 
     /// Reads a player's info from a string.
     ///
-    /// All errors with parsing the data, is
-    /// propagated up to the caller.
+    /// The version header is read first, with a missing header
+    /// meaning version 0, and the rest of the data is parsed
+    /// according to that version. All errors with parsing the
+    /// data, including an unsupported version, is propagated
+    /// up to the caller.
     fn from_str<E>(
         player_data: &str,
         bad_data_err: impl Fn() -> E + Copy,
+    ) -> Result<Option<Self>, E> {
+        /* read all the lines in the file */
+        let lines_in_file: Vec<&str> = database::strip_bom(player_data).lines().collect();
+
+        let version = lines_in_file.first().and_then(|&line| {
+            database::Entry::<&str, _, ()>::from_line(line, identity)
+                .filter(|entry| entry.key == "Version")
+        });
+        match version {
+            None => Self::from_lines_v0(&lines_in_file, bad_data_err),
+            Some(version) => match version.value.parse::<usize>() {
+                Ok(1) => Self::from_lines_v1(&lines_in_file[1..], bad_data_err),
+                _ => Err(bad_data_err()),
+            },
+        }
+    }
+
+    /// Reads a player's info from the lines of a version 1 file,
+    /// not including the version header.
+    ///
+    /// Version 1 stores the same fields as version 0, but the
+    /// username and words played are escaped (see [`database::escape`]).
+    fn from_lines_v1<E>(
+        lines_in_file: &[&str],
+        bad_data_err: impl Fn() -> E + Copy,
+    ) -> Result<Option<Self>, E> {
+        Self::from_lines(lines_in_file, true, bad_data_err)
+    }
+
+    /// Reads a player's info from the lines of a legacy file
+    /// without a version header.
+    ///
+    /// Legacy files predate escaping, so the username and words
+    /// played are read as-is.
+    fn from_lines_v0<E>(
+        lines_in_file: &[&str],
+        bad_data_err: impl Fn() -> E + Copy,
+    ) -> Result<Option<Self>, E> {
+        Self::from_lines(lines_in_file, false, bad_data_err)
+    }
+
+    /// Reads a player's info from the lines of a file, where
+    /// `escaped` is whether the username and words played
+    /// are escaped.
+    ///
+    /// The first five lines are required, and any lines added
+    /// to the format later are optional.
+    fn from_lines<E>(
+        lines_in_file: &[&str],
+        escaped: bool,
+        bad_data_err: impl Fn() -> E + Copy,
     ) -> Result<Option<Self>, E> {
         /* use a borrowed string as the key for all entries */
         type DatabaseEntry<'a, T, V> = database::Entry<&'a str, T, V>;
 
-        /* read all the lines in the file */
-        let lines_in_file: Vec<&str> = player_data.lines().collect();
-
-        if lines_in_file.len() != 5 {
+        if lines_in_file.len() < 5 {
             /* corrupt database file */
             return Err(bad_data_err());
         }
 
         /* parse the lines in the file */
+        let read_value = if escaped {
+            database::unescape
+        } else {
+            str::to_owned
+        };
         let username =
-            DatabaseEntry::from_line(lines_in_file[0], str::to_owned).ok_or_else(bad_data_err)?;
-        let words_played = DatabaseEntry::from_collection(lines_in_file[1], str::to_owned)
-            .ok_or_else(bad_data_err)?;
+            DatabaseEntry::from_line(lines_in_file[0], read_value).ok_or_else(bad_data_err)?;
+        let words_played: HashSet<_> = if escaped {
+            DatabaseEntry::from_collection(lines_in_file[1], database::unescape)
+                .ok_or_else(bad_data_err)?
+                .value
+        } else {
+            /* without escaping, every delimiter separates two words */
+            let entry = DatabaseEntry::<_, ()>::from_line(lines_in_file[1], identity)
+                .ok_or_else(bad_data_err)?;
+            (!entry.value.is_empty())
+                .then(|| entry.value.split(database::ITEM_DELIM).map(str::to_owned))
+                .into_iter()
+                .flatten()
+                .collect()
+        };
         let num_guesses_list =
             DatabaseEntry::<Vec<_>, _>::try_from_collection(lines_in_file[2], str::parse::<usize>)
                 .map_err(|_| bad_data_err())?
@@ -376,27 +1624,218 @@ impl PlayerInfo<String> {
             .map_err(|_| bad_data_err())?
             .ok_or_else(bad_data_err)?;
 
-        /* parse the number of guesses into an array */
-        let num_guesses = {
-            let mut num_guesses = [0; 6];
-            num_guesses_list
-                .value
-                .into_iter()
-                .take(num_guesses.len())
-                .enumerate()
-                .for_each(|(i, num_guess)| num_guesses[i] = num_guess);
-            num_guesses
-        };
+        /* parse the number of guesses into an array, which must be the right size */
+        let num_guesses: [usize; MAX_GUESSES] = num_guesses_list
+            .value
+            .try_into()
+            .map_err(|_| bad_data_err())?;
+
+        let mut player = Self::load_counting_losses(
+            username.value,
+            words_played,
+            num_guesses,
+            max_win_streak.value,
+            cur_win_streak.value,
+        );
+
+        /* parse any optional lines, which may be missing from older files */
+        let mut has_losses = false;
+        for &line in &lines_in_file[5..] {
+            let entry =
+                DatabaseEntry::<_, ()>::from_line(line, identity).ok_or_else(bad_data_err)?;
+            match entry.key {
+                "Losses" => {
+                    player.losses = entry.value.parse().map_err(|_| bad_data_err())?;
+                    has_losses = true;
+                }
+                "Hard Mode Violations" => {
+                    player.hard_mode_violations =
+                        entry.value.parse().map_err(|_| bad_data_err())?;
+                }
+                "Game History" => {
+                    player.game_history = DatabaseEntry::<Vec<_>, _>::try_from_collection(
+                        line,
+                        |result| match result {
+                            "W" => Ok(true),
+                            "L" => Ok(false),
+                            _ => Err(()),
+                        },
+                    )
+                    .map_err(|_| bad_data_err())?
+                    .ok_or_else(bad_data_err)?
+                    .value;
+                }
+                "Track Streaks" => {
+                    player.track_streaks = entry.value.parse().map_err(|_| bad_data_err())?;
+                }
+                _ => return Err(bad_data_err()),
+            }
+        }
+
+        /* files without losses predate imported wins, so every game was a word played */
+        let games = if has_losses {
+            player.games_played()
+        } else {
+            player.words_played.len()
+        };
+        if !player.is_consistent(games) {
+            return Err(bad_data_err());
+        }
+
+        Ok(Some(player))
+    }
+
+    /// Checks that this player's statistics are consistent,
+    /// with `games` as the number of games played.
+    ///
+    /// The statistics must pass the checks made by [`PlayerInfo::try_load`],
+    /// and every word played must have been either won or lost.
+    fn is_consistent(&self, games: usize) -> bool {
+        check_stats(
+            self.games_won(),
+            games,
+            self.max_win_streak,
+            self.cur_win_streak,
+        )
+        .is_ok()
+            && self.words_played.len() <= self.games_won() + self.losses
+    }
+}
+
+/// Checks that a player's statistics are consistent.
+///
+/// See [`PlayerInfo::try_load`] for the checks that are made.
+fn check_stats(
+    wins: usize,
+    games: usize,
+    max_win_streak: usize,
+    cur_win_streak: usize,
+) -> Result<(), WordleError> {
+    if cur_win_streak > max_win_streak {
+        return Err(WordleError::StreakExceedsMax {
+            current: cur_win_streak,
+            max: max_win_streak,
+        });
+    }
+    if wins > games {
+        return Err(WordleError::WinsExceedGames { wins, games });
+    }
+    if max_win_streak > wins {
+        return Err(WordleError::StreakExceedsWins {
+            streak: max_win_streak,
+            wins,
+        });
+    }
+    Ok(())
+}
+
+/// Gets the header row for player statistics exported
+/// with [`PlayerInfo::to_csv_row`].
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::players::{self, PlayerInfo};
+/// let player = PlayerInfo::new("user");
+/// let csv = format!("{}\n{}\n", players::csv_header(), player.to_csv_row());
+/// ```
+#[inline]
+#[must_use]
+pub const fn csv_header() -> &'static str {
+    "username,words_played,wins,losses,max_win_streak,cur_win_streak,\
+    guesses_1,guesses_2,guesses_3,guesses_4,guesses_5,guesses_6"
+}
+
+/// Gets the word for a shared puzzle.
+///
+/// The words in the dictionary are sorted, and the puzzle number
+/// wraps around to pick one of them, so the same puzzle number
+/// and dictionary always result in the same word. If the
+/// dictionary is empty, this function returns [`None`].
+///
+/// # Examples:
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::players;
+/// let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+/// let word = players::get_puzzle_word(&dictionary, 1234);
+/// assert_eq!(word, players::get_puzzle_word(&dictionary, 1234));
+/// ```
+#[must_use]
+pub fn get_puzzle_word(
+    dictionary: &HashSet<String, impl std::hash::BuildHasher>,
+    puzzle_id: u32,
+) -> Option<&str> {
+    let words = sorted_words(dictionary);
+    if words.is_empty() {
+        return None;
+    }
+    Some(words[puzzle_id as usize % words.len()])
+}
+
+/// Gets every word in a dictionary in sorted order.
+fn sorted_words(dictionary: &HashSet<String, impl std::hash::BuildHasher>) -> Vec<&str> {
+    let mut words: Vec<_> = dictionary.iter().map(String::as_str).collect();
+    words.sort_unstable();
+    words
+}
+
+/// Loads the player files for many users at once.
+///
+/// Each user's data is read from `<username>.txt` inside `data_dir`.
+/// The files are read and parsed across several threads, and the
+/// result of loading each user is returned in the order of the
+/// usernames, so an error for one user does not affect the others.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```no_run
+/// # use std::{collections::BTreeSet, path::Path};
+/// # use wordle::players;
+/// let usernames = BTreeSet::from([String::from("alice"), String::from("bob")]);
+/// for (username, player) in players::load_players_parallel(&usernames, Path::new(".")) {
+///     match player {
+///         Ok(Some(player)) => println!("{}", player.get_stats()),
+///         Ok(None) => println!("{username} has no saved data"),
+///         Err(msg) => println!("{msg}"),
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn load_players_parallel(
+    usernames: &BTreeSet<String>,
+    data_dir: &Path,
+) -> Vec<(String, io::Result<Option<PlayerInfo<String>>>)> {
+    let usernames: Vec<_> = usernames.iter().collect();
+    let num_threads = thread::available_parallelism().map_or(1, usize::from);
+    /* split the users evenly between the threads */
+    let chunk_size = usernames.len().div_ceil(num_threads).max(1);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = usernames
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|&username| {
+                            let filename = data_dir.join(username.clone() + ".txt");
+                            (username.clone(), PlayerInfo::from_file(filename))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
 
-        let player = Self::load(
-            username.value,
-            words_played.value,
-            num_guesses,
-            max_win_streak.value,
-            cur_win_streak.value,
-        );
-        Ok(Some(player))
-    }
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
 }
 
 /// from_file isn't doc tested since it requires a file. The
@@ -407,6 +1846,12 @@ mod test {
     use super::*;
     use std::io;
 
+    /// Creates the error returned for corrupt player data,
+    /// for the tests that parse player data.
+    fn bad_data_err() -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, "Error: corrupt player data")
+    }
+
     #[test]
     fn from_str_is_parsed() {
         let player_data = "Username: player\n\
@@ -419,9 +1864,905 @@ mod test {
 
         let player = PlayerInfo::from_str(player_data, bad_data_err).unwrap();
 
-        let mut expected_player = PlayerInfo::new(String::from("player"));
-        expected_player.add_lost_word(String::from("TRACE"));
+        /* older files have no history of games, and every word not won was lost */
+        let expected_player = PlayerInfo::try_load(
+            String::from("player"),
+            HashSet::from([String::from("TRACE")]),
+            [0; MAX_GUESSES],
+            0,
+            0,
+        )
+        .unwrap();
 
         assert_eq!(player.unwrap(), expected_player);
     }
+
+    #[test]
+    fn from_str_reads_both_streaks() {
+        let player_data = "Username: player\n\
+            Words Played: BLIND,CRATE,TRACE,SPEED\n\
+            Number of Guesses: 0,1,1,1,0,0\n\
+            Maximum Win Streak: 2\n\
+            Current Win Streak: 1";
+
+        let player = PlayerInfo::from_str(player_data, bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert_eq!(player.get_max_win_streak(), 2);
+        assert_eq!(player.get_cur_win_streak(), 1);
+    }
+
+    #[test]
+    fn empty_words_played_round_trip() {
+        let player = PlayerInfo::new(String::from("player"));
+
+        let restored = PlayerInfo::from_str(&player.to_string(), bad_data_err)
+            .unwrap()
+            .unwrap();
+
+        assert!(restored.words_played.is_empty());
+        assert_eq!(restored, player);
+    }
+
+    #[test]
+    fn try_load_checks_invariants() {
+        let words_played: HashSet<_> = [String::from("TRACE"), String::from("CRATE")].into();
+        let num_guesses = [0, 1, 1, 0, 0, 0];
+
+        let player = PlayerInfo::try_load("player", words_played.clone(), num_guesses, 2, 1);
+        assert_eq!(
+            player.unwrap(),
+            PlayerInfo::load("player", words_played.clone(), num_guesses, 2, 1)
+        );
+
+        assert_eq!(
+            PlayerInfo::try_load("player", words_played.clone(), num_guesses, 1, 2).unwrap_err(),
+            WordleError::StreakExceedsMax { current: 2, max: 1 }
+        );
+        assert_eq!(
+            PlayerInfo::try_load("player", words_played.clone(), [0, 1, 1, 1, 0, 0], 2, 1)
+                .unwrap_err(),
+            WordleError::WinsExceedGames { wins: 3, games: 2 }
+        );
+        assert_eq!(
+            PlayerInfo::try_load("player", words_played, [0, 1, 0, 0, 0, 0], 2, 1).unwrap_err(),
+            WordleError::StreakExceedsWins { streak: 2, wins: 1 }
+        );
+    }
+
+    #[test]
+    fn from_str_checks_same_invariants() {
+        let player_data = "Username: player\n\
+            Words Played: TRACE,CRATE\n\
+            Number of Guesses: 0,1,1,0,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 2";
+        assert!(PlayerInfo::from_str(player_data, bad_data_err).is_err());
+
+        /* removing a won word keeps the win, which is still a game played */
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 2);
+        player.add_lost_word(String::from("CRATE"));
+        assert!(player.remove_played_word("TRACE"));
+        assert_eq!(player.games_played(), 2);
+        assert!(player
+            .get_stats()
+            .starts_with("Number of Games Played: 2\n"));
+
+        let restored = PlayerInfo::from_str(&player.to_string(), bad_data_err).unwrap();
+        assert_eq!(restored, Some(player));
+    }
+
+    #[test]
+    fn recent_win_rate_shows_trend() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        let words = [
+            "TRACE", "BLIND", "CRATE", "SPEED", "CRANE", "BEBOP", "SLATE", "POINT",
+        ];
+        /* lost the first half of the games, then won the rest */
+        for (i, word) in words.into_iter().enumerate() {
+            if i < words.len() / 2 {
+                player.add_lost_word(word.to_owned());
+            } else {
+                player.add_won_word(word.to_owned(), 4);
+            }
+        }
+
+        assert_eq!(player.stats().win_rate, 50);
+        assert_eq!(player.recent_win_rate(4), Some(100.0));
+        assert_eq!(player.recent_win_rate(5), Some(80.0));
+        /* more games than have been played uses every game */
+        assert_eq!(player.recent_win_rate(100), Some(50.0));
+        assert_eq!(player.recent_win_rate(0), None);
+        assert!(player.get_stats().contains("Win Rate: 50% (Last 8: 50%)"));
+
+        let restored = PlayerInfo::from_str(&player.to_string(), bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert_eq!(restored.recent_win_rate(4), Some(100.0));
+
+        /* only the most recent games are shown once there are enough of them */
+        let mut player = restored;
+        for word in ["GHOST", "PLANT", "BRICK", "FLAME"] {
+            player.add_won_word(word.to_owned(), 4);
+        }
+        assert!(player.get_stats().contains("(Last 10: 80%)"));
+    }
+
+    #[test]
+    fn stats_win_rate_decimals() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_lost_word(String::from("BLIND"));
+
+        let whole = player.get_stats_with(&StatsFormat {
+            win_rate_decimals: 0,
+        });
+        let one_decimal = player.get_stats_with(&StatsFormat {
+            win_rate_decimals: 1,
+        });
+
+        assert_eq!(whole, player.get_stats());
+        assert!(whole.contains("Win Rate: 33% (Last 3: 33%)"));
+        assert!(one_decimal.contains("Win Rate: 33.3% (Last 3: 33.3%)"));
+        assert_eq!(
+            whole.replace("33%", "33.3%"),
+            one_decimal,
+            "only the win rates should differ"
+        );
+    }
+
+    #[test]
+    fn from_str_with_bom() {
+        let player_data = "\u{feff}Username: player\n\
+            Words Played: TRACE\n\
+            Number of Guesses: 0,0,1,0,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 1";
+
+        let player = PlayerInfo::from_str(player_data, bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert_eq!(player.get_username(), "player");
+    }
+
+    #[test]
+    fn favorite_initials_by_count() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        for word in [
+            "SLATE", "SPEED", "STORM", "CRATE", "CRANE", "BLIND", "TRACE",
+        ] {
+            player.add_won_word(word.to_owned(), 4);
+        }
+
+        assert_eq!(player.favorite_initials(3), [('S', 3), ('C', 2), ('B', 1)]);
+        assert_eq!(player.favorite_initials(10).len(), 4);
+        assert!(player.favorite_initials(0).is_empty());
+    }
+
+    #[test]
+    fn streak_goal_with_few_words_left() {
+        let dictionary: HashSet<_> = ["BLIND", "CRATE", "SPEED", "TRACE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 4);
+        /* not in the dictionary, so it does not use up a word */
+        player.add_won_word(String::from("BEBOP"), 5);
+
+        assert_eq!(player.words_until_exhausted(&dictionary), 2);
+        assert!(player.can_reach_streak(&dictionary, 5));
+        assert!(!player.can_reach_streak(&dictionary, 6));
+
+        player.add_lost_word(String::from("SPEED"));
+        assert_eq!(player.words_until_exhausted(&dictionary), 1);
+        assert!(player.can_reach_streak(&dictionary, 1));
+        assert!(!player.can_reach_streak(&dictionary, 2));
+    }
+
+    #[test]
+    fn max_streak_ignores_removed_words() {
+        let dictionary: HashSet<_> = ["TRACE", "CRATE", "BLIND"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let mut player = PlayerInfo::new("player");
+        assert_eq!(player.max_possible_streak(&dictionary), 3);
+
+        /* a word that is no longer in the dictionary still adds to the streak */
+        player.add_won_word(String::from("BEBOP"), 2);
+        player.add_won_word(String::from("TRACE"), 4);
+        assert_eq!(player.max_possible_streak(&dictionary), 4);
+
+        player.add_lost_word(String::from("CRATE"));
+        assert_eq!(player.max_possible_streak(&dictionary), 1);
+    }
+
+    #[test]
+    fn streaks_stay_zero_when_not_tracked() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("BLIND"), 2);
+        player.set_track_streaks(false);
+        assert_eq!(player.get_cur_win_streak(), 0);
+        /* the best streak is kept, but not updated */
+        assert_eq!(player.get_max_win_streak(), 1);
+
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_distribution(&[0, 1, 0, 0, 0, 0], true);
+        player.add_won_word(String::from("CRANE"), 4);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_won_word(String::from("CRATE"), 4);
+        assert_eq!(player.get_cur_win_streak(), 0);
+        assert_eq!(player.get_max_win_streak(), 1);
+        assert_eq!(player.games_won(), 5);
+        assert!(!player.get_stats().contains("Win Streak"));
+
+        /* the preference is saved with the player */
+        let restored = PlayerInfo::from_str(&player.to_string(), bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert!(!restored.tracks_streaks());
+        assert_eq!(restored, player);
+
+        player.set_track_streaks(true);
+        player.add_won_word(String::from("SPEED"), 5);
+        assert_eq!(player.get_cur_win_streak(), 1);
+        assert!(player.get_stats().contains("Current Win Streak: 1"));
+        assert!(player.get_stats().contains("Maximum Win Streak: 1"));
+    }
+
+    #[test]
+    fn achievements_for_crafted_player() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        for i in 0..6 {
+            player.add_won_word(format!("WORD{i}"), 3);
+        }
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_won_word(String::from("TRACE"), MAX_GUESSES);
+        assert_eq!(
+            player.achievements(),
+            [
+                Achievement::FirstWin,
+                Achievement::Streak5,
+                Achievement::CloseCall
+            ]
+        );
+
+        player.add_distribution(&[1, 0, 2, 0, 0, 0], false);
+        assert_eq!(
+            player.achievements(),
+            [
+                Achievement::FirstWin,
+                Achievement::TenWins,
+                Achievement::Streak5,
+                Achievement::GeniusSolve,
+                Achievement::CloseCall
+            ]
+        );
+    }
+
+    #[test]
+    fn distribution_bars_are_scaled() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        assert_eq!(player.distribution_bars(20), [(0, 0); MAX_GUESSES]);
+
+        player.add_distribution(&[1, 3, 8, 6, 2, 0], false);
+        assert_eq!(
+            player.distribution_bars(20),
+            [(1, 3), (3, 8), (8, 20), (6, 15), (2, 5), (0, 0)]
+        );
+        assert_eq!(player.distribution_bars(0)[2], (8, 0));
+    }
+
+    #[test]
+    fn distribution_percentages_in_stats() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        assert!(player.get_stats().contains("\n3:  0\n"));
+
+        player.add_distribution(&[0, 2, 4, 3, 1, 0], false);
+        let stats = player.get_stats();
+        assert!(stats.contains("\n2: ====== 2 (20%)\n"));
+        assert!(stats.contains("\n3: ============ 4 (40%)\n"));
+        assert!(stats.contains("\n4: ========= 3 (30%)\n"));
+        assert!(stats.contains("\n6:  0 (0%)\n"));
+    }
+
+    #[test]
+    fn reset_stats_keeps_username() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_hard_mode_violation();
+
+        player.reset_stats();
+        assert_eq!(player.get_username(), "player");
+        assert_eq!(player, PlayerInfo::new(String::from("player")));
+        assert!(player
+            .get_stats()
+            .starts_with("Number of Games Played: 0\n"));
+    }
+
+    #[test]
+    fn csv_export() {
+        assert_eq!(
+            csv_header(),
+            "username,words_played,wins,losses,max_win_streak,cur_win_streak,\
+            guesses_1,guesses_2,guesses_3,guesses_4,guesses_5,guesses_6"
+        );
+        assert_eq!(csv_header().split(',').count(), 6 + MAX_GUESSES);
+
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 5);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_won_word(String::from("BLIND"), 3);
+        assert_eq!(player.to_csv_row(), "player,4,3,1,2,1,0,0,2,0,1,0");
+
+        let player = PlayerInfo::new("the \"best\", player");
+        assert_eq!(
+            player.to_csv_row(),
+            "\"the \"\"best\"\", player\",0,0,0,0,0,0,0,0,0,0,0"
+        );
+    }
+
+    #[test]
+    fn removed_word_can_be_played_again() {
+        let dictionary = HashSet::from([String::from("TRACE")]);
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        assert_eq!(player.get_random_word(&dictionary), None);
+
+        assert!(!player.remove_played_word("CRATE"));
+        assert!(player.remove_played_word("TRACE"));
+        assert!(!player.remove_played_word("TRACE"));
+        assert_eq!(player.get_random_word(&dictionary), Some("TRACE"));
+        assert_eq!(player.num_guesses, [0, 0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rename_keeps_stats() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_won_word(String::from("CRATE"), 5);
+
+        let renamed = player.clone().rename(String::from("renamed"));
+
+        assert_eq!(renamed.get_username(), "renamed");
+        assert_eq!(renamed.words_played, player.words_played);
+        assert_eq!(renamed.num_guesses, player.num_guesses);
+        assert_eq!(renamed.max_win_streak, player.max_win_streak);
+        assert_eq!(renamed.cur_win_streak, player.cur_win_streak);
+    }
+
+    #[test]
+    fn replayed_word_is_not_double_counted() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        assert!(player.add_won_word(String::from("TRACE"), 3));
+        assert!(!player.add_won_word(String::from("TRACE"), 4));
+        assert!(player.add_lost_word(String::from("BLIND")));
+        assert!(!player.add_lost_word(String::from("BLIND")));
+
+        assert_eq!(player.words_played.len(), 2);
+        assert_eq!(player.num_guesses, [0, 0, 1, 0, 0, 0]);
+        assert_eq!(player.games_lost(), 1);
+        assert_eq!(player.cur_win_streak, 0);
+        assert_eq!(player.max_win_streak, 1);
+        assert_eq!(player.game_history, [true, false]);
+
+        /* the replays keep the file consistent */
+        let path = std::env::temp_dir().join(format!(
+            "wordle-rs-{}-replayed-word.txt",
+            std::process::id()
+        ));
+        let filename = path.to_str().unwrap();
+        player.write_to_file(filename).unwrap();
+        let read_player = PlayerInfo::from_file(filename).unwrap();
+        std::fs::remove_file(filename).unwrap();
+
+        assert_eq!(read_player.unwrap(), player);
+    }
+
+    #[test]
+    fn games_played_won_and_lost() {
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BEBOP"));
+        player.add_won_word(String::from("CRATE"), 1);
+        player.add_won_word(String::from("BLIND"), 6);
+        player.add_lost_word(String::from("ABBEY"));
+
+        assert_eq!(player.games_played(), 5);
+        assert_eq!(player.games_won(), 3);
+        assert_eq!(player.games_lost(), 2);
+    }
+
+    #[test]
+    fn completion_ignores_stale_words() {
+        let dictionary = HashSet::from([
+            String::from("TRACE"),
+            String::from("CRATE"),
+            String::from("BLIND"),
+            String::from("ABBEY"),
+        ]);
+
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BLIND"));
+        /* no longer in the dictionary */
+        player.add_won_word(String::from("BEBOP"), 2);
+
+        assert_eq!(player.completion_ratio(&dictionary), 0.5);
+        assert_eq!(player.completion_ratio(&HashSet::new()), 0.0);
+    }
+
+    #[test]
+    fn same_puzzle_id_same_word() {
+        let words = ["TRACE", "CRATE", "BLIND", "ABBEY", "BEBOP", "CRANE"];
+        let dictionary: HashSet<_> = words.iter().map(|&w| w.to_owned()).collect();
+        let rev_dictionary: HashSet<_> = words.iter().rev().map(|&w| w.to_owned()).collect();
+
+        for puzzle_id in [0, 1, 1234, u32::MAX] {
+            let word = get_puzzle_word(&dictionary, puzzle_id);
+            assert!(word.is_some());
+            assert_eq!(word, get_puzzle_word(&rev_dictionary, puzzle_id));
+        }
+        assert_eq!(get_puzzle_word(&HashSet::new(), 1234), None);
+    }
+
+    #[test]
+    fn random_puzzle_is_unplayed() {
+        let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+
+        let (puzzle_id, word) = player.get_random_puzzle(&dictionary).unwrap();
+        assert_eq!(word, "CRATE");
+        assert_eq!(get_puzzle_word(&dictionary, puzzle_id), Some("CRATE"));
+
+        player.add_won_word(String::from("CRATE"), 3);
+        assert_eq!(player.get_random_puzzle(&dictionary), None);
+
+        /* a single unplayed word is always found */
+        let dictionary: HashSet<_> = (0..1000).map(|i| format!("WORD{i}")).collect();
+        let mut player = PlayerInfo::new("player");
+        for word in dictionary.iter().filter(|&w| w != "WORD500") {
+            player.add_won_word(word.clone(), 3);
+        }
+        for _ in 0..100 {
+            let (puzzle_id, word) = player.get_random_puzzle(&dictionary).unwrap();
+            assert_eq!(word, "WORD500");
+            assert_eq!(get_puzzle_word(&dictionary, puzzle_id), Some("WORD500"));
+        }
+    }
+
+    #[test]
+    fn random_words_are_distinct_and_unplayed() {
+        let dictionary: HashSet<_> = ["TRACE", "CRATE", "BLIND", "ABBEY", "BEBOP", "CRANE"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+        let rng = fastrand::Rng::with_seed(1234);
+
+        let words = player.get_random_words(&dictionary, 3, &rng);
+        assert_eq!(words.len(), 3);
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), 3);
+        assert!(words
+            .iter()
+            .all(|w| dictionary.contains(*w) && *w != "TRACE"));
+    }
+
+    #[test]
+    fn random_words_short_return() {
+        let dictionary = HashSet::from([String::from("TRACE"), String::from("CRATE")]);
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+        let rng = fastrand::Rng::with_seed(1234);
+
+        assert_eq!(player.get_random_words(&dictionary, 5, &rng), ["CRATE"]);
+
+        player.add_won_word(String::from("CRATE"), 3);
+        assert!(player.get_random_words(&dictionary, 5, &rng).is_empty());
+    }
+
+    #[test]
+    fn from_str_without_optional_lines() {
+        let player_data = "Username: player\n\
+            Words Played: TRACE\n\
+            Number of Guesses: 0,0,1,0,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 1";
+
+        let player = PlayerInfo::from_str(player_data, bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert_eq!(player.get_hard_mode_violations(), 0);
+    }
+
+    #[test]
+    fn hard_mode_violations_persist() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_hard_mode_violation();
+        player.add_hard_mode_violation();
+        assert_eq!(player.get_hard_mode_violations(), 2);
+        assert!(player.get_stats().contains("Hard Mode Violations: 2"));
+
+        let path = std::env::temp_dir().join(format!(
+            "wordle-rs-{}-hard-mode-violations.txt",
+            std::process::id()
+        ));
+        let filename = path.to_str().unwrap();
+        player.write_to_file(filename).unwrap();
+        let read_player = PlayerInfo::from_file(filename).unwrap();
+        std::fs::remove_file(filename).unwrap();
+
+        assert_eq!(read_player.unwrap(), player);
+    }
+
+    #[test]
+    fn average_guesses_of_won_games() {
+        let mut player = PlayerInfo::new("player");
+        player.add_lost_word(String::from("BEBOP"));
+        assert_eq!(player.average_guesses(), None);
+        assert!(!player.get_stats().contains("Average Guesses"));
+
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 5);
+        assert_eq!(player.average_guesses(), Some(4.0));
+        assert!(player.get_stats().contains("Average Guesses: 4.00\n"));
+
+        player.add_won_word(String::from("BLIND"), 1);
+        assert_eq!(player.average_guesses(), Some(3.0));
+    }
+
+    #[test]
+    fn legacy_and_versioned_files() {
+        let body = "Username: player\n\
+            Words Played: TRACE,CRATE\n\
+            Number of Guesses: 0,0,1,0,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 0\n";
+        let legacy = PlayerInfo::from_str(body, || ()).unwrap().unwrap();
+        let versioned = PlayerInfo::from_str(&format!("Version: 1\n{body}"), || ())
+            .unwrap()
+            .unwrap();
+        assert_eq!(legacy, versioned);
+        assert_eq!(legacy.games_lost(), 1);
+
+        /* a legacy file is upgraded when it is written again */
+        let upgraded = legacy.to_string();
+        assert!(upgraded.starts_with("Version: 1\n"));
+        assert_eq!(PlayerInfo::from_str(&upgraded, || ()), Ok(Some(legacy)));
+
+        let future = PlayerInfo::from_str(&format!("Version: 2\n{body}"), || ());
+        assert_eq!(future, Err(()));
+    }
+
+    #[test]
+    fn legacy_values_are_not_unescaped() {
+        let legacy = "Username: back\\slash\\n\n\
+            Words Played: TRACE,CRATE\n\
+            Number of Guesses: 0,0,1,0,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 0\n";
+        let player = PlayerInfo::from_str(legacy, || ()).unwrap().unwrap();
+        assert_eq!(player.get_username(), "back\\slash\\n");
+        assert_eq!(player.games_played(), 2);
+
+        /* the same value is escaped once the file is upgraded */
+        let upgraded = player.to_string();
+        assert!(upgraded.contains("Username: back\\\\slash\\\\n\n"));
+        assert_eq!(PlayerInfo::from_str(&upgraded, || ()), Ok(Some(player)));
+    }
+
+    #[test]
+    fn delimiters_in_words_round_trip() {
+        let mut player = PlayerInfo::new(String::from("player,one"));
+        player.add_won_word(String::from("A,B\nC"), 2);
+        player.add_lost_word(String::from("TRACE"));
+
+        let path = std::env::temp_dir().join(format!(
+            "wordle-rs-{}-escaped-words.txt",
+            std::process::id()
+        ));
+        let filename = path.to_str().unwrap();
+        player.write_to_file(filename).unwrap();
+        let read_player = PlayerInfo::from_file(filename).unwrap();
+        std::fs::remove_file(filename).unwrap();
+
+        assert_eq!(read_player.unwrap(), player);
+    }
+
+    #[test]
+    fn players_load_in_parallel() {
+        let data_dir = std::env::temp_dir().join(format!(
+            "wordle-rs-{}-load-players-parallel",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&data_dir).unwrap();
+
+        let usernames: BTreeSet<_> = (0..10).map(|i| format!("player{i}")).collect();
+        let mut expected_players = Vec::new();
+        for (i, username) in usernames.iter().enumerate() {
+            let mut player = PlayerInfo::new(username.clone());
+            player.add_won_word(format!("WORD{i}"), i % 6 + 1);
+            player
+                .write_to_file(data_dir.join(username.clone() + ".txt").to_str().unwrap())
+                .unwrap();
+            expected_players.push(player);
+        }
+        /* a user without a file and a user with a corrupt file */
+        let mut all_usernames = usernames.clone();
+        all_usernames.insert(String::from("missing"));
+        all_usernames.insert(String::from("corrupt"));
+        std::fs::write(data_dir.join("corrupt.txt"), "not a player").unwrap();
+
+        let loaded = load_players_parallel(&all_usernames, &data_dir);
+        std::fs::remove_dir_all(&data_dir).unwrap();
+
+        assert_eq!(loaded.len(), all_usernames.len());
+        for (username, player) in loaded {
+            match username.as_str() {
+                "missing" => assert!(player.unwrap().is_none()),
+                "corrupt" => {
+                    assert_eq!(player.unwrap_err().kind(), io::ErrorKind::InvalidData);
+                }
+                _ => {
+                    let player = player.unwrap().unwrap();
+                    assert_eq!(player.get_username(), username);
+                    assert!(expected_players.contains(&player));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stats_for_known_player() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 3);
+        player.add_lost_word(String::from("BLIND"));
+        player.add_won_word(String::from("CRANE"), 5);
+        player.add_hard_mode_violation();
+
+        let expected_stats = Stats {
+            games_played: 4,
+            games_won: 3,
+            games_lost: 1,
+            win_rate: 75,
+            current_streak: 1,
+            max_streak: 2,
+            distribution: [0, 0, 2, 0, 1, 0],
+            hard_mode_violations: 1,
+        };
+        assert_eq!(player.stats(), expected_stats);
+    }
+
+    #[test]
+    fn win_rate_in_stats() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        /* no games played, so there is nothing to divide by */
+        assert_eq!(player.stats().win_rate, 0);
+        assert!(player.get_stats().contains("Win Rate: 0%\n"));
+
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 4);
+        player.add_lost_word(String::from("BLIND"));
+        /* 2 of 3 games rounds up */
+        assert!(player.get_stats().contains("Win Rate: 67%"));
+
+        player.add_won_word(String::from("CRANE"), 5);
+        assert_eq!(player.games_won(), 3);
+        assert_eq!(player.games_played(), 4);
+        assert!(player.get_stats().contains("Win Rate: 75%"));
+    }
+
+    #[test]
+    fn losses_round_trip() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_lost_word(String::from("BLIND"));
+        player.add_lost_word(String::from("BEBOP"));
+        assert_eq!(player.games_lost(), 2);
+        assert_eq!(player.games_played(), 3);
+        assert!(player.to_string().contains("Losses: 2\n"));
+        assert!(player.get_stats().contains("Losses: 2\n"));
+
+        let restored = PlayerInfo::from_str(&player.to_string(), bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert_eq!(restored, player);
+    }
+
+    #[test]
+    fn losses_from_old_file() {
+        /* older files have no losses, so every word that was not won was lost */
+        let player_data = "Username: player\n\
+            Words Played: BLIND,CRATE,TRACE\n\
+            Number of Guesses: 0,0,1,0,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 0";
+
+        let player = PlayerInfo::from_str(player_data, bad_data_err)
+            .unwrap()
+            .unwrap();
+        assert_eq!(player.games_lost(), 2);
+        assert_eq!(player.games_played(), 3);
+    }
+
+    #[test]
+    fn best_game_in_stats() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        assert!(player.get_stats().contains("Best: no wins yet"));
+
+        player.add_won_word(String::from("TRACE"), 5);
+        player.add_won_word(String::from("CRATE"), 3);
+        player.add_won_word(String::from("CRANE"), 4);
+        assert!(player.get_stats().contains("Best: 3 guesses"));
+
+        player.add_won_word(String::from("BLIND"), 1);
+        assert!(player.get_stats().contains("Best: 1 guess\n"));
+    }
+
+    #[test]
+    fn from_str_rejects_wrong_bucket_count() {
+        for num_guesses in ["0,0,1,0,0,0,0", "0,0,1,0,0"] {
+            let player_data = format!(
+                "Username: player\n\
+                Words Played: TRACE\n\
+                Number of Guesses: {num_guesses}\n\
+                Maximum Win Streak: 1\n\
+                Current Win Streak: 1"
+            );
+
+            let err = PlayerInfo::from_str(&player_data, bad_data_err).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn from_str_rejects_more_wins_than_games() {
+        let player_data = "Username: player\n\
+            Words Played: TRACE\n\
+            Number of Guesses: 0,0,1,1,0,0\n\
+            Maximum Win Streak: 1\n\
+            Current Win Streak: 1";
+
+        assert!(PlayerInfo::from_str(player_data, bad_data_err).is_err());
+
+        /* the same wins can be loaded once the losses show some were imported */
+        let player_data = format!("{player_data}\nLosses: 0");
+        assert!(PlayerInfo::from_str(&player_data, bad_data_err).is_ok());
+
+        /* but the words played must still have all been won or lost */
+        let player_data = player_data.replace("TRACE", "TRACE,CRATE,BLIND");
+        assert!(PlayerInfo::from_str(&player_data, bad_data_err).is_err());
+    }
+
+    #[test]
+    fn imported_wins_are_saved() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_distribution(&[0, 1, 0, 0, 0, 0], false);
+        assert_eq!(player.games_played(), 2);
+
+        let restored = PlayerInfo::from_str(&player.to_string(), bad_data_err).unwrap();
+        assert_eq!(restored, Some(player));
+    }
+
+    #[test]
+    fn unplayed_words_are_sorted() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("CRATE"), 3);
+        player.add_lost_word(String::from("SPEED"));
+
+        let dictionary: BTreeSet<_> = ["TRACE", "SPEED", "BLIND", "CRATE", "CRANE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let unplayed: Vec<_> = player.unplayed_iter(&dictionary).collect();
+        assert_eq!(unplayed, ["BLIND", "CRANE", "TRACE"]);
+    }
+
+    #[test]
+    fn distribution_is_merged() {
+        let mut player = PlayerInfo::new(String::from("player"));
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 4);
+        player.add_lost_word(String::from("BLIND"));
+
+        player.add_distribution(&[1, 0, 2, 0, 0, 1], false);
+        assert_eq!(player.stats().distribution, [1, 0, 3, 1, 0, 1]);
+        assert_eq!(player.games_won(), 6);
+        /* the imported wins are games played as well */
+        assert_eq!(player.games_played(), 7);
+        assert_eq!(player.get_cur_win_streak(), 0);
+        assert_eq!(player.get_max_win_streak(), 2);
+
+        player.add_distribution(&[0, 0, 0, 3, 0, 0], true);
+        assert_eq!(player.get_cur_win_streak(), 3);
+        assert_eq!(player.get_max_win_streak(), 3);
+    }
+
+    #[test]
+    fn to_string_is_stable() {
+        let words = ["TRACE", "BLIND", "CRATE", "SPEED", "CRANE"];
+        let orders: [Vec<_>; 2] = [words.to_vec(), words.into_iter().rev().collect()];
+        let players: Vec<_> = orders
+            .into_iter()
+            .map(|words| {
+                let mut player = PlayerInfo::new("player");
+                for word in words {
+                    player.add_won_word(word.to_owned(), 3);
+                }
+                player
+            })
+            .collect();
+
+        let expected = "Version: 1\n\
+            Username: player\n\
+            Words Played: BLIND,CRANE,CRATE,SPEED,TRACE\n\
+            Number of Guesses: 0,0,5,0,0,0\n\
+            Maximum Win Streak: 5\n\
+            Current Win Streak: 5\n\
+            Losses: 0\n\
+            Hard Mode Violations: 0\n\
+            Game History: W,W,W,W,W\n\
+            Track Streaks: true\n";
+        assert_eq!(players[0].to_string(), expected);
+        assert_eq!(players[1].to_string(), expected);
+    }
+
+    #[test]
+    fn word_with_selector_is_unplayed() {
+        use selector::{AlphabeticalSelector, RandomSelector};
+
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("BLIND"), 4);
+        let dictionary: HashSet<_> = ["TRACE", "BLIND", "CRATE"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+
+        assert_eq!(
+            player.get_word_with(&dictionary, &mut AlphabeticalSelector),
+            Some("CRATE")
+        );
+        let word = player
+            .get_word_with(&dictionary, &mut RandomSelector::with_seed(1))
+            .unwrap();
+        assert!(word == "CRATE" || word == "TRACE");
+
+        player.add_won_word(String::from("CRATE"), 4);
+        player.add_won_word(String::from("TRACE"), 4);
+        assert_eq!(
+            player.get_word_with(&dictionary, &mut AlphabeticalSelector),
+            None
+        );
+    }
+
+    #[test]
+    fn heatmap_counts_letter_positions() {
+        let mut player = PlayerInfo::new("player");
+        player.add_won_word(String::from("TRACE"), 3);
+        player.add_won_word(String::from("CRATE"), 4);
+        /* invalid words are skipped */
+        player.add_lost_word(String::from("CRAT3"));
+        player.add_lost_word(String::from("TRACES"));
+
+        let heatmap = player.position_letter_heatmap();
+        let count = |pos: usize, c: u8| heatmap[pos][usize::from(c - b'A')];
+        assert_eq!(count(0, b'T'), 1);
+        assert_eq!(count(0, b'C'), 1);
+        assert_eq!(count(1, b'R'), 2);
+        assert_eq!(count(2, b'A'), 2);
+        assert_eq!(count(4, b'E'), 2);
+        assert_eq!(heatmap.iter().flatten().sum::<usize>(), 10);
+    }
 }
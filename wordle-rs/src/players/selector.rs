@@ -0,0 +1,119 @@
+//! This module provides strategies for choosing which
+//! word a player should play next.
+//!
+//! Author: Benjamin Hall
+
+/// Chooses a word from a list of candidate words.
+///
+/// This lets different game modes choose words in different
+/// ways. See [`PlayerInfo::get_word_with`](super::PlayerInfo::get_word_with).
+pub trait WordSelector {
+    /// Chooses one of the candidates, or returns None if there
+    /// are no candidates to choose from.
+    fn select<'a>(&mut self, candidates: &[&'a str]) -> Option<&'a str>;
+}
+
+/// Chooses a candidate uniformly at random.
+#[derive(Debug, Clone)]
+pub struct RandomSelector {
+    rng: fastrand::Rng,
+}
+
+impl RandomSelector {
+    /// Creates a random selector with a randomly seeded generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::selector::{RandomSelector, WordSelector};
+    /// let mut selector = RandomSelector::new();
+    /// assert!(selector.select(&["TRACE", "CRATE"]).is_some());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rng: fastrand::Rng::new(),
+        }
+    }
+
+    /// Creates a random selector with the given seed, so
+    /// the same candidates always result in the same choices.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```
+    /// # use wordle::players::selector::{RandomSelector, WordSelector};
+    /// let candidates = ["TRACE", "CRATE", "CRANE"];
+    /// let word = RandomSelector::with_seed(7).select(&candidates);
+    /// assert_eq!(word, RandomSelector::with_seed(7).select(&candidates));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: fastrand::Rng::with_seed(seed),
+        }
+    }
+}
+
+impl Default for RandomSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WordSelector for RandomSelector {
+    fn select<'a>(&mut self, candidates: &[&'a str]) -> Option<&'a str> {
+        if candidates.is_empty() {
+            None
+        } else {
+            Some(candidates[self.rng.usize(0..candidates.len())])
+        }
+    }
+}
+
+/// Chooses the first candidate in alphabetical order.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::players::selector::{AlphabeticalSelector, WordSelector};
+/// assert_eq!(AlphabeticalSelector.select(&["TRACE", "CRATE"]), Some("CRATE"));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AlphabeticalSelector;
+
+impl WordSelector for AlphabeticalSelector {
+    fn select<'a>(&mut self, candidates: &[&'a str]) -> Option<&'a str> {
+        candidates.iter().min().copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn random_selector_chooses_candidate() {
+        let candidates = ["TRACE", "CRATE", "CRANE"];
+        let mut selector = RandomSelector::with_seed(42);
+        for _ in 0..10 {
+            let word = selector.select(&candidates).unwrap();
+            assert!(candidates.contains(&word));
+        }
+        assert_eq!(selector.select(&[]), None);
+    }
+
+    #[test]
+    fn alphabetical_selector_chooses_first() {
+        assert_eq!(
+            AlphabeticalSelector.select(&["TRACE", "BLIND", "CRATE"]),
+            Some("BLIND")
+        );
+        assert_eq!(AlphabeticalSelector.select(&[]), None);
+    }
+}
@@ -0,0 +1,398 @@
+//! This module provides methods to share the results
+//! of a game of Wordle as a grid of colored squares,
+//! and to render guess results for different frontends.
+//!
+//! Author: Benjamin Hall
+
+use std::fmt::Write;
+
+#[cfg(feature = "player_db")]
+use std::borrow::Borrow;
+
+#[cfg(feature = "player_db")]
+use crate::{players::PlayerInfo, GameResult};
+use crate::{WordleGuess, MAX_GUESSES, WORD_LEN};
+
+/// Renders a guess result for display.
+///
+/// Different frontends may want different glyphs for each
+/// result, such as letters, emoji, or terminal colors.
+pub trait GuessRenderer {
+    /// Renders a single guess result.
+    fn render(&self, guess: WordleGuess) -> String;
+}
+
+/// Renders guess results as letters.
+///
+/// - G -> [`WordleGuess::Correct`]
+/// - Y -> [`WordleGuess::Present`]
+/// - X -> [`WordleGuess::Incorrect`]
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{share::{GuessRenderer, LetterRenderer}, WordleGuess};
+/// assert_eq!(LetterRenderer.render(WordleGuess::Present), "Y");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct LetterRenderer;
+
+impl GuessRenderer for LetterRenderer {
+    fn render(&self, guess: WordleGuess) -> String {
+        guess.to_string()
+    }
+}
+
+/// Colors of the emoji squares used to share a game.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum EmojiTheme {
+    /// Green and yellow squares
+    ///
+    /// - 🟩 -> [`WordleGuess::Correct`]
+    /// - 🟨 -> [`WordleGuess::Present`]
+    /// - ⬛ -> [`WordleGuess::Incorrect`]
+    #[default]
+    Standard,
+    /// Orange and blue squares, which are easier to tell
+    /// apart for players who are color blind
+    ///
+    /// - 🟧 -> [`WordleGuess::Correct`]
+    /// - 🟦 -> [`WordleGuess::Present`]
+    /// - ⬛ -> [`WordleGuess::Incorrect`]
+    HighContrast,
+}
+
+/// Renders guess results as emoji squares, using the
+/// colors of an [`EmojiTheme`].
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{share::{EmojiRenderer, EmojiTheme, GuessRenderer}, WordleGuess};
+/// assert_eq!(EmojiRenderer::default().render(WordleGuess::Correct), "🟩");
+///
+/// let renderer = EmojiRenderer { theme: EmojiTheme::HighContrast };
+/// assert_eq!(renderer.render(WordleGuess::Correct), "🟧");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct EmojiRenderer {
+    /// Colors of the squares
+    pub theme: EmojiTheme,
+}
+
+impl GuessRenderer for EmojiRenderer {
+    fn render(&self, guess: WordleGuess) -> String {
+        match (self.theme, guess) {
+            (EmojiTheme::Standard, WordleGuess::Correct) => "🟩",
+            (EmojiTheme::Standard, WordleGuess::Present) => "🟨",
+            (EmojiTheme::HighContrast, WordleGuess::Correct) => "🟧",
+            (EmojiTheme::HighContrast, WordleGuess::Present) => "🟦",
+            (_, WordleGuess::Incorrect) => "⬛",
+        }
+        .to_owned()
+    }
+}
+
+/// Renders guess results as letters with ANSI terminal colors.
+///
+/// Each letter is shown in black on a background of its color,
+/// followed by a reset so later text is not colored.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{share::{AnsiRenderer, GuessRenderer}, WordleGuess};
+/// assert_eq!(AnsiRenderer.render(WordleGuess::Correct), "\x1b[30;42mG\x1b[0m");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AnsiRenderer;
+
+impl GuessRenderer for AnsiRenderer {
+    fn render(&self, guess: WordleGuess) -> String {
+        let color = match guess {
+            WordleGuess::Correct => 42,
+            WordleGuess::Present => 43,
+            WordleGuess::Incorrect => 47,
+        };
+        format!("\x1b[30;{color}m{guess}\x1b[0m")
+    }
+}
+
+/// Renders the results of one guess as a row.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, share::{self, EmojiRenderer, LetterRenderer}};
+/// let row = guess_result![X Y X X G];
+/// assert_eq!(share::render_row(&LetterRenderer, &row), "XYXXG");
+/// assert_eq!(share::render_row(&EmojiRenderer::default(), &row), "⬛🟨⬛⬛🟩");
+/// ```
+#[must_use]
+pub fn render_row<R: GuessRenderer>(renderer: &R, row: &[WordleGuess; WORD_LEN]) -> String {
+    row.iter().map(|&guess| renderer.render(guess)).collect()
+}
+
+/// Creates the header line for a shared game of Wordle.
+///
+/// If the game was won in `n` guesses, the header shows
+/// "n/6". If the game was lost, the header shows "X/6".
+/// If the game was a numbered puzzle, the header also
+/// shows the puzzle number so others can play it.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::share;
+/// assert_eq!(share::share_header(Some(4), None), "Wordle 4/6");
+/// assert_eq!(share::share_header(None, None), "Wordle X/6");
+/// assert_eq!(share::share_header(Some(3), Some(1234)), "Wordle #1234 3/6");
+/// ```
+#[must_use]
+pub fn share_header(num_guesses: Option<usize>, puzzle_id: Option<u32>) -> String {
+    let mut header = String::from("Wordle ");
+    if let Some(puzzle_id) = puzzle_id {
+        write!(header, "#{puzzle_id} ").unwrap();
+    }
+    match num_guesses {
+        Some(num_guesses) => write!(header, "{num_guesses}/{MAX_GUESSES}").unwrap(),
+        None => write!(header, "X/{MAX_GUESSES}").unwrap(),
+    }
+    header
+}
+
+/// Creates a grid of emoji squares from the results of each guess.
+///
+/// Each row of the grid is the result of one guess.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, share};
+/// let rows = [guess_result![X Y X X G], guess_result![G G G G G]];
+/// assert_eq!(share::share_grid(&rows), "⬛🟨⬛⬛🟩\n🟩🟩🟩🟩🟩");
+/// ```
+#[inline]
+#[must_use]
+pub fn share_grid(rows: &[[WordleGuess; WORD_LEN]]) -> String {
+    share_grid_with(rows, EmojiTheme::Standard)
+}
+
+/// Creates a grid of emoji squares from the results of each
+/// guess, using the colors of the given theme.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, share::{self, EmojiTheme}};
+/// let rows = [guess_result![X Y X X G], guess_result![G G G G G]];
+/// assert_eq!(
+///     share::share_grid_with(&rows, EmojiTheme::HighContrast),
+///     "⬛🟦⬛⬛🟧\n🟧🟧🟧🟧🟧"
+/// );
+/// ```
+#[must_use]
+pub fn share_grid_with(rows: &[[WordleGuess; WORD_LEN]], theme: EmojiTheme) -> String {
+    let renderer = EmojiRenderer { theme };
+    let mut grid = String::new();
+    for row in rows {
+        writeln!(grid, "{}", render_row(&renderer, row)).unwrap();
+    }
+    grid.trim_end().to_owned()
+}
+
+/// Creates a block of text to share the result of a game.
+///
+/// The block contains the header and grid of the game. If a
+/// player is given, a line with their statistics is added
+/// after a blank line.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, players::PlayerInfo, share, GameResult};
+/// let result = GameResult {
+///     answer: String::from("TRACE"),
+///     history: vec![(String::from("TRACE"), guess_result![G G G G G])],
+///     num_guesses: Some(1),
+/// };
+/// let text = share::game_share_text(&result, None, None::<&PlayerInfo<&str>>);
+/// assert_eq!(text, "Wordle 1/6\n🟩🟩🟩🟩🟩");
+/// ```
+#[cfg(feature = "player_db")]
+#[inline]
+#[must_use]
+pub fn game_share_text<S: Borrow<str>>(
+    result: &GameResult,
+    puzzle_id: Option<u32>,
+    include_stats: Option<&PlayerInfo<S>>,
+) -> String {
+    game_share_text_with(result, puzzle_id, include_stats, EmojiTheme::Standard)
+}
+
+/// Creates a block of text to share the result of a game,
+/// using the colors of the given theme for the grid.
+///
+/// See [`game_share_text`] for the contents of the block.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, players::PlayerInfo, share::{self, EmojiTheme}, GameResult};
+/// let result = GameResult {
+///     answer: String::from("TRACE"),
+///     history: vec![(String::from("TRACE"), guess_result![G G G G G])],
+///     num_guesses: Some(1),
+/// };
+/// let text = share::game_share_text_with(
+///     &result,
+///     None,
+///     None::<&PlayerInfo<&str>>,
+///     EmojiTheme::HighContrast,
+/// );
+/// assert_eq!(text, "Wordle 1/6\n🟧🟧🟧🟧🟧");
+/// ```
+#[cfg(feature = "player_db")]
+#[must_use]
+pub fn game_share_text_with<S: Borrow<str>>(
+    result: &GameResult,
+    puzzle_id: Option<u32>,
+    include_stats: Option<&PlayerInfo<S>>,
+    theme: EmojiTheme,
+) -> String {
+    let mut text = share_header(result.num_guesses, puzzle_id);
+    writeln!(text).unwrap();
+    text.push_str(&share_grid_with(&result.rows(), theme));
+
+    if let Some(player) = include_stats {
+        let stats = player.stats();
+        write!(
+            text,
+            "\n\nPlayed: {} | Win Rate: {}% | Streak: {} | Max Streak: {}",
+            stats.games_played, stats.win_rate, stats.current_streak, stats.max_streak
+        )
+        .unwrap();
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{guess_result, WordleAnswer};
+
+    #[test]
+    fn won_game_grid() {
+        let answer = WordleAnswer::new("TRACE");
+        let rows: Vec<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(|guess| answer.check_guess(guess))
+            .collect();
+
+        assert_eq!(share_header(Some(rows.len()), None), "Wordle 3/6");
+        assert_eq!(share_grid(&rows), "⬛⬛⬛⬛⬛\n🟨🟩🟩🟨🟩\n🟩🟩🟩🟩🟩");
+    }
+
+    #[test]
+    fn lost_game_grid() {
+        let answer = WordleAnswer::new("TRACE");
+        let rows: Vec<_> = ["BLIND", "CRATE", "CRANE", "GRACE", "BRACE", "SPACE"]
+            .into_iter()
+            .map(|guess| answer.check_guess(guess))
+            .collect();
+
+        let grid = share_grid(&rows);
+        assert_eq!(share_header(None, Some(42)), "Wordle #42 X/6");
+        assert_eq!(grid.lines().count(), 6);
+        assert!(grid.lines().all(|row| row != "🟩🟩🟩🟩🟩"));
+    }
+
+    #[test]
+    fn letter_renderer_row() {
+        let row = guess_result![G Y X X G];
+        assert_eq!(render_row(&LetterRenderer, &row), "GYXXG");
+    }
+
+    #[test]
+    fn emoji_renderer_row() {
+        let row = guess_result![G Y X X G];
+        assert_eq!(render_row(&EmojiRenderer::default(), &row), "🟩🟨⬛⬛🟩");
+    }
+
+    #[test]
+    fn emoji_theme_squares() {
+        let squares = |theme| {
+            let renderer = EmojiRenderer { theme };
+            [
+                WordleGuess::Correct,
+                WordleGuess::Present,
+                WordleGuess::Incorrect,
+            ]
+            .map(|guess| renderer.render(guess))
+        };
+        assert_eq!(squares(EmojiTheme::Standard), ["🟩", "🟨", "⬛"]);
+        assert_eq!(squares(EmojiTheme::HighContrast), ["🟧", "🟦", "⬛"]);
+
+        let rows = [guess_result![G Y X X G]];
+        assert_eq!(
+            share_grid_with(&rows, EmojiTheme::Standard),
+            share_grid(&rows)
+        );
+    }
+
+    #[test]
+    fn ansi_renderer_row() {
+        let row = guess_result![G Y X X G];
+        assert_eq!(
+            render_row(&AnsiRenderer, &row),
+            concat!(
+                "\x1b[30;42mG\x1b[0m",
+                "\x1b[30;43mY\x1b[0m",
+                "\x1b[30;47mX\x1b[0m",
+                "\x1b[30;47mX\x1b[0m",
+                "\x1b[30;42mG\x1b[0m"
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "player_db")]
+    fn share_text_for_won_game() {
+        let answer = WordleAnswer::new("TRACE");
+        let history: Vec<_> = ["BLIND", "CRATE", "TRACE"]
+            .into_iter()
+            .map(|guess| (guess.to_owned(), answer.check_guess(guess)))
+            .collect();
+        let result = GameResult {
+            answer: String::from("TRACE"),
+            history,
+            num_guesses: Some(3),
+        };
+
+        let mut player = PlayerInfo::new("player");
+        player.add_lost_word(String::from("CRANE"));
+        player.add_won_word(String::from("TRACE"), 3);
+
+        let expected = [
+            "Wordle #42 3/6",
+            "⬛⬛⬛⬛⬛",
+            "🟨🟩🟩🟨🟩",
+            "🟩🟩🟩🟩🟩",
+            "",
+            "Played: 2 | Win Rate: 50% | Streak: 1 | Max Streak: 1",
+        ];
+        assert_eq!(
+            game_share_text(&result, Some(42), Some(&player)),
+            expected.join("\n")
+        );
+    }
+}
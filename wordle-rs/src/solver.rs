@@ -0,0 +1,970 @@
+//! This module provides methods to analyze guesses in a
+//! game of Wordle, such as how much information a guess
+//! is expected to reveal about the answer.
+//!
+//! Author: Benjamin Hall
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::BuildHasher,
+};
+
+use crate::{LetterCounts, WordleAnswer, WordleGuess, WORD_LEN};
+
+/// Calculates the entropy of a guess against a set of candidate answers.
+///
+/// Each candidate is checked against the guess, and the candidates
+/// are grouped by the resulting pattern of colors. The entropy (in bits)
+/// of the distribution of patterns is then returned. A higher entropy
+/// means the guess is expected to narrow down the candidates further.
+///
+/// Candidates that are not the same length as the guess could never
+/// be its answer, so they are skipped. If there are no candidates
+/// left, this function returns 0.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::solver;
+/// let candidates = [String::from("TRACE"), String::from("BLIND")];
+/// assert_eq!(solver::guess_entropy("TRACE", &candidates), 1.0);
+/// assert_eq!(solver::guess_entropy("FFFFF", &candidates), 0.0);
+/// ```
+#[must_use]
+pub fn guess_entropy(guess: &str, candidates: &[String]) -> f64 {
+    let guess_len = guess.chars().count();
+    let mut pattern_counts = HashMap::new();
+    let mut num_candidates = 0usize;
+    for candidate in candidates.iter().filter(|c| c.chars().count() == guess_len) {
+        let pattern = WordleAnswer::new(candidate.as_str()).check_guess(guess);
+        *pattern_counts.entry(pattern).or_insert(0usize) += 1;
+        num_candidates += 1;
+    }
+
+    let num_candidates = num_candidates as f64;
+    pattern_counts
+        .into_values()
+        .map(|count| {
+            let p = count as f64 / num_candidates;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Determines which of two guesses is better against a set of candidates.
+///
+/// The better guess is the one with the higher [`guess_entropy`].
+/// If both guesses have the same entropy, the first guess is returned.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::solver;
+/// let candidates = [String::from("TRACE"), String::from("BLIND")];
+/// assert_eq!(solver::better_guess("FFFFF", "TRACE", &candidates), "TRACE");
+/// ```
+#[must_use]
+pub fn better_guess<'a>(a: &'a str, b: &'a str, candidates: &[String]) -> &'a str {
+    if guess_entropy(b, candidates) > guess_entropy(a, candidates) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Checks that a history of guesses is consistent with an answer.
+///
+/// Each guess in the history is checked against the answer, and the
+/// result is compared to the colors recorded in the history. If any
+/// recorded colors do not match, or any guess is not the same length
+/// as the answer, this function returns false.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let history = [
+///     (String::from("BLIND"), guess_result![X X X X X]),
+///     (String::from("CRATE"), guess_result![Y G G Y G]),
+/// ];
+/// assert!(solver::history_is_consistent("TRACE", &history));
+/// ```
+#[must_use]
+pub fn history_is_consistent(answer: &str, history: &[(String, [WordleGuess; WORD_LEN])]) -> bool {
+    first_inconsistency(answer, history).is_none()
+}
+
+/// Finds the first guess in a history that is not consistent
+/// with an answer.
+///
+/// Each guess in the history is checked against the answer, and
+/// the index of the first guess whose recorded colors do not match
+/// is returned. A guess that is not the same length as the answer
+/// never matches. If every guess matches, this function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let history = [
+///     (String::from("BLIND"), guess_result![X X X X X]),
+///     (String::from("CRATE"), guess_result![G G G Y G]),
+/// ];
+/// assert_eq!(solver::first_inconsistency("TRACE", &history), Some(1));
+/// ```
+#[must_use]
+pub fn first_inconsistency(
+    answer: &str,
+    history: &[(String, [WordleGuess; WORD_LEN])],
+) -> Option<usize> {
+    let answer_len = answer.chars().count();
+    let answer = WordleAnswer::new(answer);
+    history.iter().position(|(guess, colors)| {
+        guess.chars().count() != answer_len || answer.check_guess(guess) != *colors
+    })
+}
+
+/// Finds all letters known to be absent from the answer.
+///
+/// A letter is absent if it was marked Incorrect in a guess and was
+/// never marked Present or Correct in any guess. A letter that is gray
+/// in one position but green or yellow elsewhere is a duplicate of a
+/// letter in the answer, so it is not considered absent.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use wordle::{guess_result, solver};
+/// let history = [(String::from("SPEED"), guess_result![X X G X X])];
+/// assert_eq!(
+///     solver::absent_letters(&history),
+///     BTreeSet::from(['S', 'P', 'D'])
+/// );
+/// ```
+#[must_use]
+pub fn absent_letters(history: &[(String, [WordleGuess; WORD_LEN])]) -> BTreeSet<char> {
+    let mut gray_letters = BTreeSet::new();
+    let mut found_letters = BTreeSet::new();
+    for (guess, colors) in history {
+        for (c, color) in guess.chars().zip(colors) {
+            if *color == WordleGuess::Incorrect {
+                gray_letters.insert(c);
+            } else {
+                found_letters.insert(c);
+            }
+        }
+    }
+
+    &gray_letters - &found_letters
+}
+
+/// Suggests the best next guess given a history of guesses.
+///
+/// The dictionary is first filtered down to the words that are
+/// still possible answers given the history, skipping any words
+/// that are not [`WORD_LEN`] letters long. The candidate with the
+/// highest [`guess_entropy`] against the other candidates is then
+/// returned. Ties are broken by dictionary order.
+///
+/// If no words in the dictionary are consistent with the history,
+/// this function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let dictionary = [
+///     String::from("BLIND"),
+///     String::from("CRATE"),
+///     String::from("TRACE"),
+/// ];
+/// let history = [(String::from("BLIND"), guess_result![X X X X X])];
+/// let suggestion = solver::suggest(&history, &dictionary);
+/// assert_eq!(suggestion.as_deref(), Some("CRATE"));
+/// ```
+#[must_use]
+pub fn suggest(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    dictionary: &[String],
+) -> Option<String> {
+    let candidates = possible_answers(history, dictionary);
+
+    let mut best_guess: Option<(&String, f64)> = None;
+    for candidate in &candidates {
+        let entropy = guess_entropy(candidate, &candidates);
+        if best_guess.is_none_or(|(_, best_entropy)| entropy > best_entropy) {
+            best_guess = Some((candidate, entropy));
+        }
+    }
+    best_guess.map(|(guess, _)| guess.clone())
+}
+
+/// Suggests the best next guess given a history of guesses,
+/// where the guess does not have to be a possible answer.
+///
+/// Unlike [`suggest`], any word in the dictionary that is
+/// [`WORD_LEN`] letters long may be suggested.
+/// The word with the highest [`guess_entropy`] against the words that
+/// are still possible answers is returned. Ties are broken in favour of
+/// a possible answer, and then by dictionary order. If `hard_mode` is
+/// true, only words allowed by [`hard_mode_allows`] are suggested.
+///
+/// If no words in the dictionary are consistent with the history,
+/// this function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let dictionary = [
+///     String::from("HIKER"),
+///     String::from("HLPMZ"),
+///     String::from("LIKER"),
+///     String::from("MIKER"),
+/// ];
+/// let history = [(String::from("BAKER"), guess_result![X X G G G])];
+/// let suggestion = solver::suggest_with(&history, &dictionary, false);
+/// assert_eq!(suggestion.as_deref(), Some("HLPMZ"));
+/// let suggestion = solver::suggest_with(&history, &dictionary, true);
+/// assert_eq!(suggestion.as_deref(), Some("HIKER"));
+/// ```
+#[must_use]
+pub fn suggest_with(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    dictionary: &[String],
+    hard_mode: bool,
+) -> Option<String> {
+    let candidates = possible_answers(history, dictionary);
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let mut best_guess: Option<(&String, f64, bool)> = None;
+    for guess in dictionary.iter().filter(|word| has_word_len(word)) {
+        if hard_mode && !hard_mode_allows(history, guess) {
+            continue;
+        }
+        let entropy = guess_entropy(guess, &candidates);
+        let is_candidate = candidates.contains(guess);
+        let is_better = best_guess.is_none_or(|(_, best_entropy, best_is_candidate)| {
+            entropy > best_entropy
+                || (entropy == best_entropy && is_candidate && !best_is_candidate)
+        });
+        if is_better {
+            best_guess = Some((guess, entropy, is_candidate));
+        }
+    }
+    best_guess.map(|(guess, _, _)| guess.clone())
+}
+
+/// Checks whether a guess follows the rules of hard mode.
+///
+/// Every green letter from earlier guesses must be in the
+/// same position, and every green or yellow letter must be
+/// used at least as many times as it has been revealed.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+/// assert!(solver::hard_mode_allows(&history, "TRACE"));
+/// assert!(!solver::hard_mode_allows(&history, "BLIND"));
+/// ```
+#[must_use]
+pub fn hard_mode_allows(history: &[(String, [WordleGuess; WORD_LEN])], guess: &str) -> bool {
+    let constraints = constraints_from_history(history);
+
+    let greens_kept = constraints
+        .greens
+        .iter()
+        .zip(guess.chars())
+        .all(|(green, letter)| green.is_none_or(|green| green == letter));
+    let letters_used = constraints
+        .min_counts
+        .iter()
+        .all(|(&letter, &count)| guess.chars().filter(|&c| c == letter).count() >= count.into());
+
+    greens_kept && letters_used
+}
+
+/// Calculates how much information is still needed to find
+/// the answer given a history of guesses.
+///
+/// This is the base-2 logarithm of the number of words in the
+/// dictionary that are still possible answers given the history,
+/// skipping any words that are not [`WORD_LEN`] letters long,
+/// so 0 means the answer is known. If no words are consistent
+/// with the history, this function also returns 0.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let dictionary = [
+///     String::from("BLIND"),
+///     String::from("CRATE"),
+///     String::from("TRACE"),
+///     String::from("SPEED"),
+/// ];
+/// assert_eq!(solver::remaining_entropy(&[], &dictionary), 2.0);
+///
+/// let history = [(String::from("BLIND"), guess_result![X X X X X])];
+/// assert_eq!(solver::remaining_entropy(&history, &dictionary), 1.0);
+/// ```
+#[must_use]
+pub fn remaining_entropy(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    dictionary: &[String],
+) -> f64 {
+    let num_candidates = possible_answers(history, dictionary).len();
+
+    if num_candidates == 0 {
+        0.0
+    } else {
+        (num_candidates as f64).log2()
+    }
+}
+
+/// Checks whether a word is [`WORD_LEN`] letters long.
+fn has_word_len(word: &str) -> bool {
+    word.chars().count() == WORD_LEN
+}
+
+/// Finds the words in a dictionary that are still possible
+/// answers given a history of guesses.
+///
+/// Words that are not [`WORD_LEN`] letters long are skipped.
+fn possible_answers(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    dictionary: &[String],
+) -> Vec<String> {
+    dictionary
+        .iter()
+        .filter(|word| has_word_len(word) && history_is_consistent(word, history))
+        .cloned()
+        .collect()
+}
+
+/// Finds the best first guess for a set of possible answers.
+///
+/// Each guess is scored by its [`guess_entropy`] against the
+/// answers. If `answers_only` is true, only the answers themselves
+/// are considered as guesses, so the opener could also be the answer.
+/// Otherwise, every word in `allowed` is considered, which may find
+/// a more informative opener that can never be the answer. Ties are
+/// broken by the order of the guesses.
+///
+/// If there are no guesses to consider, this function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::solver;
+/// let answers = [String::from("BAKER"), String::from("BIKER")];
+/// let allowed = [String::from("AAAAA"), String::from("BAKER"), String::from("BIKER")];
+/// assert_eq!(solver::best_opener(&answers, &allowed, true).as_deref(), Some("BAKER"));
+/// ```
+#[must_use]
+pub fn best_opener(answers: &[String], allowed: &[String], answers_only: bool) -> Option<String> {
+    let guesses = if answers_only { answers } else { allowed };
+
+    let mut best_guess: Option<(&String, f64)> = None;
+    for guess in guesses {
+        let entropy = guess_entropy(guess, answers);
+        if best_guess.is_none_or(|(_, best_entropy)| entropy > best_entropy) {
+            best_guess = Some((guess, entropy));
+        }
+    }
+    best_guess.map(|(guess, _)| guess.clone())
+}
+
+/// Suggests the most informative letter the player has not yet guessed.
+///
+/// Every letter that has not appeared in any guess is considered.
+/// The best letter is the one whose presence or absence splits the
+/// candidates most evenly. Ties are broken alphabetically.
+///
+/// If no unguessed letter splits the candidates, such as when
+/// only one candidate remains, this function returns None.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let candidates = [String::from("HIKER"), String::from("LIKER")];
+/// let history = [(String::from("BAKER"), guess_result![X X G G G])];
+/// assert_eq!(solver::suggest_letter(&history, &candidates), Some('H'));
+/// ```
+#[must_use]
+pub fn suggest_letter(
+    history: &[(String, [WordleGuess; WORD_LEN])],
+    candidates: &[String],
+) -> Option<char> {
+    let guessed_letters: HashSet<char> = history
+        .iter()
+        .flat_map(|(guess, _)| guess.chars())
+        .collect();
+
+    let mut best_letter: Option<(char, usize)> = None;
+    for c in ('A'..='Z').filter(|c| !guessed_letters.contains(c)) {
+        let num_with_letter = candidates.iter().filter(|w| w.contains(c)).count();
+        let split = std::cmp::min(num_with_letter, candidates.len() - num_with_letter);
+        if split > 0 && best_letter.is_none_or(|(_, best_split)| split > best_split) {
+            best_letter = Some((c, split));
+        }
+    }
+    best_letter.map(|(c, _)| c)
+}
+
+/// Stores the constraints a history of guesses places on the answer.
+///
+/// The constraints can be used to quickly filter a list of words
+/// down to the possible answers with [`matches`], rather than
+/// checking every guess in the history against every word.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Constraints {
+    /// Letters known to be at each position
+    pub greens: [Option<char>; WORD_LEN],
+    /// Letters known not to be at each position
+    pub not_at: [HashSet<char>; WORD_LEN],
+    /// Minimum number of times each letter appears in the answer
+    pub min_counts: HashMap<char, u8>,
+    /// Maximum number of times each letter appears in the answer
+    ///
+    /// A letter only has a maximum once it has been marked
+    /// Incorrect, in which case the maximum is the number of times
+    /// the letter was marked Correct or Present in that guess.
+    pub max_counts: HashMap<char, u8>,
+}
+
+/// Extracts the constraints a history of guesses places on the answer.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+/// let constraints = solver::constraints_from_history(&history);
+/// assert_eq!(constraints.greens, [None, Some('R'), Some('A'), None, Some('E')]);
+/// assert!(solver::matches("TRACE", &constraints));
+/// ```
+#[must_use]
+pub fn constraints_from_history(history: &[(String, [WordleGuess; WORD_LEN])]) -> Constraints {
+    let mut constraints = Constraints::default();
+
+    for (guess, colors) in history {
+        /* count the instances of each letter found in this guess */
+        let mut found_counts: HashMap<char, u8> = HashMap::new();
+        let mut has_gray = HashSet::new();
+
+        for (((c, color), green), not_at) in guess
+            .chars()
+            .zip(colors)
+            .zip(constraints.greens.iter_mut())
+            .zip(constraints.not_at.iter_mut())
+        {
+            match color {
+                WordleGuess::Correct => *green = Some(c),
+                WordleGuess::Present => _ = not_at.insert(c),
+                WordleGuess::Incorrect => {
+                    not_at.insert(c);
+                    has_gray.insert(c);
+                }
+            }
+            if *color != WordleGuess::Incorrect {
+                *found_counts.entry(c).or_insert(0) += 1;
+            }
+        }
+
+        for (&c, &count) in &found_counts {
+            let min_count = constraints.min_counts.entry(c).or_insert(0);
+            *min_count = std::cmp::max(*min_count, count);
+        }
+        for c in has_gray {
+            /* every instance of the letter in the answer has been found */
+            let count = found_counts.get(&c).copied().unwrap_or(0);
+            let max_count = constraints.max_counts.entry(c).or_insert(count);
+            *max_count = std::cmp::min(*max_count, count);
+        }
+    }
+
+    constraints
+}
+
+/// Determines whether a word satisfies a set of constraints.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+/// let constraints = solver::constraints_from_history(&history);
+/// assert!(solver::matches("TRACE", &constraints));
+/// assert!(!solver::matches("CRANE", &constraints));
+/// ```
+#[must_use]
+pub fn matches(word: &str, constraints: &Constraints) -> bool {
+    let mut letter_counts: HashMap<char, u8> = HashMap::new();
+    for ((c, green), not_at) in word
+        .chars()
+        .zip(&constraints.greens)
+        .zip(&constraints.not_at)
+    {
+        if green.is_some_and(|green| green != c) || not_at.contains(&c) {
+            return false;
+        }
+        *letter_counts.entry(c).or_insert(0) += 1;
+    }
+
+    let count = |c| letter_counts.get(c).copied().unwrap_or(0);
+    constraints
+        .min_counts
+        .iter()
+        .all(|(c, &min_count)| count(c) >= min_count)
+        && constraints
+            .max_counts
+            .iter()
+            .all(|(c, &max_count)| count(c) <= max_count)
+}
+
+/// Determines whether a candidate could still be the answer
+/// given the colors already shown for earlier guesses.
+///
+/// This gives the same result as checking the candidate against
+/// every guess with [`history_is_consistent`], but without building
+/// an answer. A candidate that is not [`WORD_LEN`] letters long is
+/// never possible.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::{guess_result, solver};
+/// let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+/// assert!(solver::is_answer_possible("TRACE", &history));
+/// assert!(!solver::is_answer_possible("CRANE", &history));
+/// ```
+#[must_use]
+pub fn is_answer_possible(candidate: &str, history: &[(String, [WordleGuess; WORD_LEN])]) -> bool {
+    candidate.chars().count() == WORD_LEN && matches(candidate, &constraints_from_history(history))
+}
+
+/// Compares the results of two guesses position by position.
+///
+/// Each position is [`Ordering::Greater`] if the result improved
+/// from `prev` to `next`, [`Ordering::Equal`] if it stayed the same,
+/// and [`Ordering::Less`] if it got worse, using the ordering
+/// Incorrect < Present < Correct.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::cmp::Ordering;
+/// # use wordle::{guess_result, solver};
+/// let diff = solver::row_diff(&guess_result![X Y G X X], &guess_result![Y Y G X G]);
+/// assert_eq!(diff[0], Ordering::Greater);
+/// assert_eq!(diff[2], Ordering::Equal);
+/// ```
+#[must_use]
+pub fn row_diff(
+    prev: &[WordleGuess; WORD_LEN],
+    next: &[WordleGuess; WORD_LEN],
+) -> [Ordering; WORD_LEN] {
+    let mut diff = [Ordering::Equal; WORD_LEN];
+    for ((diff, prev), next) in diff.iter_mut().zip(prev).zip(next) {
+        *diff = next.cmp(prev);
+    }
+    diff
+}
+
+/// Finds all words in the dictionary that are anagrams of a word.
+///
+/// An anagram uses exactly the same letters as the word, in a
+/// different order, so it could be a guess where every letter is
+/// yellow. The word itself is not included. Words in the dictionary
+/// that are not all uppercase letters from A to Z are ignored, and
+/// if `word` is not, it has no anagrams. The anagrams are returned
+/// in alphabetical order.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::solver;
+/// let dictionary = HashSet::from([
+///     String::from("TRACE"),
+///     String::from("CRATE"),
+///     String::from("CATER"),
+///     String::from("BLIND"),
+/// ]);
+/// assert_eq!(solver::anagrams("TRACE", &dictionary), ["CATER", "CRATE"]);
+/// ```
+#[must_use]
+pub fn anagrams<'a>(word: &str, dictionary: &'a HashSet<String, impl BuildHasher>) -> Vec<&'a str> {
+    let is_uppercase = |word: &str| word.chars().all(|c| c.is_ascii_uppercase());
+    if !is_uppercase(word) {
+        return Vec::new();
+    }
+
+    let letter_counts = LetterCounts::new(word).ascii;
+    let mut anagrams: Vec<_> = dictionary
+        .iter()
+        .map(String::as_str)
+        .filter(|&other| other != word && is_uppercase(other))
+        .filter(|&other| LetterCounts::new(other).ascii == letter_counts)
+        .collect();
+    anagrams.sort_unstable();
+    anagrams
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::guess_result;
+
+    #[test]
+    fn mixed_length_words_are_skipped() {
+        let dictionary = [
+            String::from("TRACES"),
+            String::from("CRATE"),
+            String::from("ART"),
+            String::from("TRACE"),
+        ];
+        let history = [(String::from("BLIND"), guess_result![X X X X X])];
+
+        assert_eq!(guess_entropy("ART", &dictionary), 0.0);
+        assert_eq!(guess_entropy("TRACE", &dictionary), 1.0);
+        assert!(!history_is_consistent("ART", &history));
+        assert_eq!(first_inconsistency("TRACES", &history), Some(0));
+        assert_eq!(suggest(&history, &dictionary).as_deref(), Some("CRATE"));
+        assert_eq!(
+            suggest_with(&history, &dictionary, false).as_deref(),
+            Some("CRATE")
+        );
+        assert_eq!(remaining_entropy(&history, &dictionary), 1.0);
+    }
+
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|&w| w.to_owned()).collect()
+    }
+
+    #[test]
+    fn entropy_of_distinct_patterns() {
+        let candidates = words(&["BAKER", "CIDER", "FUMES", "GLOWS"]);
+        /* every candidate gives a different pattern, so 2 bits */
+        assert_eq!(guess_entropy("BIDES", &candidates), 2.0);
+    }
+
+    #[test]
+    fn better_guess_splits_candidates() {
+        let candidates = words(&["BAKER", "BIKER", "HIKER", "LIKER"]);
+        /* "KERFS" gives the same pattern for every candidate */
+        assert_eq!(guess_entropy("KERFS", &candidates), 0.0);
+        assert_eq!(better_guess("KERFS", "BAHLI", &candidates), "BAHLI");
+        assert_eq!(better_guess("BAHLI", "KERFS", &candidates), "BAHLI");
+    }
+
+    #[test]
+    fn better_guess_tie_returns_first() {
+        let candidates = words(&["TRACE"]);
+        assert_eq!(better_guess("BLIND", "FUMES", &candidates), "BLIND");
+    }
+
+    #[test]
+    fn valid_history_is_consistent() {
+        let history = [
+            (String::from("BLIND"), guess_result![X X X X X]),
+            (String::from("CRATE"), guess_result![Y G G Y G]),
+            (String::from("TRACE"), guess_result![G G G G G]),
+        ];
+        assert!(history_is_consistent("TRACE", &history));
+    }
+
+    #[test]
+    fn flipped_color_is_inconsistent() {
+        let history = [
+            (String::from("BLIND"), guess_result![X X X X X]),
+            (String::from("CRATE"), guess_result![Y G G G G]),
+        ];
+        assert!(!history_is_consistent("TRACE", &history));
+    }
+
+    #[test]
+    fn absent_letters_are_gray() {
+        let history = [
+            (String::from("BLIND"), guess_result![X X X X X]),
+            (String::from("CRATE"), guess_result![Y G G Y G]),
+        ];
+        assert_eq!(
+            absent_letters(&history),
+            BTreeSet::from(['B', 'L', 'I', 'N', 'D'])
+        );
+    }
+
+    #[test]
+    fn duplicate_found_letters_are_not_absent() {
+        let history = [
+            /* answer is "ABBEY" */
+            (String::from("EERIE"), guess_result![Y X X X X]),
+            (String::from("BOBBY"), guess_result![Y X G X G]),
+        ];
+        assert_eq!(absent_letters(&history), BTreeSet::from(['R', 'I', 'O']));
+    }
+
+    #[test]
+    fn inconsistent_row_is_found() {
+        let answer = WordleAnswer::new("TRACE");
+        let mut history: Vec<_> = ["BLIND", "CRANE", "CRATE", "TRACE"]
+            .into_iter()
+            .map(|guess| (guess.to_owned(), answer.check_guess(guess)))
+            .collect();
+        assert_eq!(first_inconsistency("TRACE", &history), None);
+        assert_eq!(first_inconsistency("TRACE", &[]), None);
+
+        /* corrupt the colors of the third and fourth rows */
+        history[2].1[0] = WordleGuess::Correct;
+        history[3].1 = guess_result![X X X X X];
+        assert_eq!(first_inconsistency("TRACE", &history), Some(2));
+        assert!(!history_is_consistent("TRACE", &history));
+    }
+
+    #[test]
+    fn suggest_after_constraining_guess() {
+        let dictionary = words(&["BAKER", "BIKER", "CIDER", "HIKER", "LIKER", "TRACE"]);
+        /* answer is "HIKER", so only HIKER and LIKER remain */
+        let history = [(String::from("BAKER"), guess_result![X X G G G])];
+
+        assert_eq!(suggest(&history, &dictionary).as_deref(), Some("HIKER"));
+    }
+
+    #[test]
+    fn hard_mode_requires_revealed_letters() {
+        let answer = WordleAnswer::new("TRACE");
+        let history = vec![(String::from("CRANE"), answer.check_guess("CRANE"))];
+
+        assert!(hard_mode_allows(&history, "TRACE"));
+        assert!(hard_mode_allows(&history, "CRATE"));
+        /* moves the green R */
+        assert!(!hard_mode_allows(&history, "CARTE"));
+        /* drops the yellow C */
+        assert!(!hard_mode_allows(&history, "TRADE"));
+    }
+
+    #[test]
+    fn hard_mode_suggestion_is_legal() {
+        let dictionary = words(&["BAKER", "HIKER", "HLPMZ", "LIKER", "MIKER", "PIKER"]);
+        /* answer is "HIKER", and HLPMZ tells the rest apart but drops the greens */
+        let history = [(String::from("BAKER"), guess_result![X X G G G])];
+
+        assert_eq!(
+            suggest_with(&history, &dictionary, false).as_deref(),
+            Some("HLPMZ")
+        );
+        assert!(!hard_mode_allows(&history, "HLPMZ"));
+        assert_eq!(
+            suggest_with(&history, &dictionary, true).as_deref(),
+            Some("HIKER")
+        );
+        assert_eq!(suggest_with(&[], &[], true), None);
+    }
+
+    #[test]
+    fn suggest_single_candidate() {
+        let dictionary = words(&["BLIND", "CRATE", "TRACE"]);
+        let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+
+        assert_eq!(suggest(&history, &dictionary).as_deref(), Some("TRACE"));
+    }
+
+    #[test]
+    fn suggest_no_candidates() {
+        let dictionary = words(&["BLIND", "CRATE"]);
+        let history = [(String::from("CRATE"), guess_result![Y G G Y G])];
+
+        assert_eq!(suggest(&history, &dictionary), None);
+    }
+
+    #[test]
+    fn constraints_from_guesses() {
+        let history = [
+            (String::from("BLIND"), guess_result![X X X X X]),
+            (String::from("CRATE"), guess_result![Y G G Y G]),
+        ];
+        let constraints = constraints_from_history(&history);
+
+        assert_eq!(
+            constraints.greens,
+            [None, Some('R'), Some('A'), None, Some('E')]
+        );
+        assert!(constraints.not_at[0].contains(&'C'));
+        assert!(constraints.not_at[3].contains(&'T'));
+        assert_eq!(constraints.min_counts[&'C'], 1);
+        assert_eq!(constraints.max_counts[&'B'], 0);
+        assert!(matches("TRACE", &constraints));
+        assert!(!matches("CRATE", &constraints));
+        assert!(!matches("BRACE", &constraints));
+    }
+
+    #[test]
+    fn constraints_duplicate_letter_min_count() {
+        /* answer is "ABBEY" */
+        let history = [(String::from("BOBBY"), guess_result![Y X G X G])];
+        let constraints = constraints_from_history(&history);
+
+        assert_eq!(constraints.min_counts[&'B'], 2);
+        assert_eq!(constraints.max_counts[&'B'], 2);
+        assert!(matches("ABBEY", &constraints));
+        /* only one B */
+        assert!(!matches("ZEBAY", &constraints));
+        /* three Bs */
+        assert!(!matches("EBBBY", &constraints));
+    }
+
+    #[test]
+    fn matches_agrees_with_check_guess() {
+        let dictionary = words(&[
+            "ABBEY", "BOBBY", "EERIE", "TRACE", "CRATE", "BLIND", "AABBB", "AZZAZ", "BACCC",
+        ]);
+        for answer in &dictionary {
+            let answer = WordleAnswer::new(answer.as_str());
+            for guess in &dictionary {
+                let history = [(guess.clone(), answer.check_guess(guess))];
+                let constraints = constraints_from_history(&history);
+                for word in &dictionary {
+                    assert_eq!(
+                        matches(word, &constraints),
+                        history_is_consistent(word, &history)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn suggest_letter_splits_evenly() {
+        let candidates = words(&["BATCH", "HATCH", "LATCH", "MATCH", "PATCH", "WATCH"]);
+        let history = [(String::from("CATCH"), guess_result![X G G G G])];
+
+        /* every letter appears in only one candidate, so the first is chosen */
+        assert_eq!(suggest_letter(&history, &candidates), Some('B'));
+
+        let candidates = words(&["BLAST", "BLACK", "CRISP", "CRUMB"]);
+        /* A, L, and R each appear in half of the candidates */
+        assert_eq!(suggest_letter(&[], &candidates), Some('A'));
+    }
+
+    #[test]
+    fn suggest_letter_skips_guessed_letters() {
+        let candidates = words(&["BIKER", "HIKER", "LIKER"]);
+        let history = [(String::from("BAKER"), guess_result![X X G G G])];
+
+        assert_eq!(suggest_letter(&history, &candidates), Some('H'));
+        assert_eq!(suggest_letter(&history, &words(&["HIKER"])), None);
+    }
+
+    #[test]
+    fn consistent_answer_is_possible() {
+        let answer = WordleAnswer::new("TRACE");
+        let history: Vec<_> = ["BLIND", "CRANE", "CRATE"]
+            .into_iter()
+            .map(|guess| (guess.to_owned(), answer.check_guess(guess)))
+            .collect();
+
+        assert!(is_answer_possible("TRACE", &history));
+        assert!(is_answer_possible("TRACE", &[]));
+    }
+
+    #[test]
+    fn inconsistent_answer_is_impossible() {
+        let answer = WordleAnswer::new("TRACE");
+        let history = vec![(String::from("CRANE"), answer.check_guess("CRANE"))];
+
+        /* uses a gray letter */
+        assert!(!is_answer_possible("CRANE", &history));
+        /* moves a green letter */
+        assert!(!is_answer_possible("CARTE", &history));
+        /* drops a yellow letter */
+        assert!(!is_answer_possible("TRADE", &history));
+        /* wrong length */
+        assert!(!is_answer_possible("TRACES", &history));
+    }
+
+    #[test]
+    fn row_diff_between_guesses() {
+        let prev = guess_result![X Y G Y G];
+        let next = guess_result![G X G Y X];
+        assert_eq!(
+            row_diff(&prev, &next),
+            [
+                Ordering::Greater,
+                Ordering::Less,
+                Ordering::Equal,
+                Ordering::Equal,
+                Ordering::Less
+            ]
+        );
+    }
+
+    #[test]
+    fn anagrams_share_letters() {
+        let dictionary: HashSet<_> = ["LISTEN", "SILENT", "TINSEL", "LISTED", "silent"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        assert_eq!(anagrams("LISTEN", &dictionary), ["SILENT", "TINSEL"]);
+        assert!(anagrams("ABCDE", &dictionary).is_empty());
+        /* a word with other letters has no anagrams, rather than panicking */
+        assert!(anagrams("listen", &dictionary).is_empty());
+        assert!(anagrams("LISTÉN", &dictionary).is_empty());
+    }
+
+    #[test]
+    fn opener_restricted_to_answers() {
+        let answers = words(&["BILLS", "FILLS", "HILLS", "KILLS", "MILLS"]);
+        let mut allowed = answers.clone();
+        allowed.push(String::from("BFHKM"));
+
+        /* any answer only tells itself apart from the rest */
+        assert_eq!(
+            best_opener(&answers, &allowed, true).as_deref(),
+            Some("BILLS")
+        );
+        /* every answer gives a different pattern for BFHKM */
+        assert_eq!(
+            best_opener(&answers, &allowed, false).as_deref(),
+            Some("BFHKM")
+        );
+        assert_eq!(best_opener(&[], &[], true), None);
+    }
+
+    #[test]
+    fn remaining_entropy_after_constraints() {
+        let dictionary = words(&["BAKER", "BIKER", "HIKER", "LIKER", "CRATE", "TRACE"]);
+        assert_eq!(remaining_entropy(&[], &dictionary), 6f64.log2());
+
+        let history = [
+            (String::from("BAKER"), guess_result![X X G G G]),
+            (String::from("HIKER"), guess_result![X G G G G]),
+        ];
+        /* only LIKER is left */
+        assert!(remaining_entropy(&history, &dictionary).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,111 @@
+//! This module provides a trait for the lists of words
+//! that guesses are checked against, so a game of Wordle
+//! is not tied to a specific collection of words.
+//!
+//! Author: Benjamin Hall
+
+use std::{collections::HashSet, hash::BuildHasher};
+
+/// A list of words that guesses are checked against.
+///
+/// See [`validate_guess`](crate::validate_guess).
+pub trait WordList {
+    /// Checks whether a word is in the list.
+    fn is_valid(&self, word: &str) -> bool;
+
+    /// Gets every word in the list, in sorted order.
+    ///
+    /// These are the words that can be suggested as guesses.
+    /// By default, no words are returned, for lists that cannot
+    /// be listed, such as a remote service.
+    fn sorted_words(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::word_list::WordList;
+/// let dictionary = HashSet::from([String::from("TRACE")]);
+/// assert!(dictionary.is_valid("TRACE"));
+/// assert!(!dictionary.is_valid("CRATE"));
+/// ```
+impl<H: BuildHasher> WordList for HashSet<String, H> {
+    #[inline]
+    fn is_valid(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+
+    fn sorted_words(&self) -> Vec<String> {
+        let mut words: Vec<_> = self.iter().cloned().collect();
+        words.sort_unstable();
+        words
+    }
+}
+
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use wordle::word_list::WordList;
+/// let words: &[&str] = &["TRACE", "CRATE"];
+/// assert!(words.is_valid("CRATE"));
+/// assert_eq!(words.sorted_words(), ["CRATE", "TRACE"]);
+/// ```
+impl WordList for &[&str] {
+    #[inline]
+    fn is_valid(&self, word: &str) -> bool {
+        self.contains(&word)
+    }
+
+    fn sorted_words(&self) -> Vec<String> {
+        let mut words: Vec<_> = self.iter().map(|&word| word.to_owned()).collect();
+        words.sort_unstable();
+        words
+    }
+}
+
+/// A list of answers combined with a list of words that are
+/// only allowed as guesses.
+///
+/// Every answer is allowed as a guess, so a word is in the
+/// combined list if it is in either list.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use std::collections::HashSet;
+/// # use wordle::word_list::{AnswersAndGuesses, WordList};
+/// let answers = HashSet::from([String::from("TRACE")]);
+/// let guesses = HashSet::from([String::from("AAHED")]);
+/// let words = AnswersAndGuesses { answers: &answers, guesses: &guesses };
+/// assert!(words.is_valid("TRACE"));
+/// assert!(words.is_valid("AAHED"));
+/// assert_eq!(words.sorted_words(), ["AAHED", "TRACE"]);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct AnswersAndGuesses<'a, A, G> {
+    /// Words that can be chosen as answers
+    pub answers: &'a A,
+    /// Words that are allowed as guesses, but are never answers
+    pub guesses: &'a G,
+}
+
+impl<A: WordList, G: WordList> WordList for AnswersAndGuesses<'_, A, G> {
+    #[inline]
+    fn is_valid(&self, word: &str) -> bool {
+        self.answers.is_valid(word) || self.guesses.is_valid(word)
+    }
+
+    fn sorted_words(&self) -> Vec<String> {
+        let mut words = self.answers.sorted_words();
+        words.extend(self.guesses.sorted_words());
+        words.sort_unstable();
+        words.dedup();
+        words
+    }
+}